@@ -1,8 +1,12 @@
-use pyo3::{pymodule, types::PyModule, Bound, PyResult, Python};
+use pyo3::{pymodule, types::PyModule, wrap_pyfunction, Bound, PyResult, Python};
 use pyo3_graphster::{
-    ConversionError, EdgeNotFoundError, GraphsterBaseError, NodeAlreadyExistsError,
-    NodeNotFoundError, PyBoolean, PyDataGraph, PyFloat32, PyFloat64, PyInt128, PyInt16, PyInt32,
-    PyInt64, PyInt8, PyString, PyUInt128, PyUInt16, PyUInt32, PyUInt64, PyUInt8, PyUsize,
+    attr, AttributeNotFoundError, ConvergenceError, ConversionError, CycleError, EdgeNotFoundError,
+    GraphsterBaseError, NegativeCycleError, NegativeWeightError, NodeAlreadyExistsError,
+    NodeNotFoundError, PyAttribute, PyBoolean, PyCriticalPathAnalysis, PyDataGraph,
+    PyDataGraphView, PyEdgeDataIterator, PyEdgeIndexIterator, PyEdgesView, PyFloat32, PyFloat64,
+    PyHeuristic, PyInt128, PyInt16, PyInt32, PyInt64, PyInt8, PyNodeDataIterator,
+    PyNodeIndexIterator, PyNodesView, PyQuery, PyString, PyTraversalIterator, PyUInt128, PyUInt16,
+    PyUInt32, PyUInt64, PyUInt8, PyUsize, QueryParseError, QueryTypeError,
 };
 
 #[pymodule]
@@ -24,6 +28,20 @@ fn _pyo3_graphster(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyUsize>()?;
 
     m.add_class::<PyDataGraph>()?;
+    m.add_class::<PyDataGraphView>()?;
+    m.add_class::<PyNodesView>()?;
+    m.add_class::<PyEdgesView>()?;
+    m.add_class::<PyNodeIndexIterator>()?;
+    m.add_class::<PyEdgeIndexIterator>()?;
+    m.add_class::<PyNodeDataIterator>()?;
+    m.add_class::<PyEdgeDataIterator>()?;
+    m.add_class::<PyTraversalIterator>()?;
+    m.add_class::<PyHeuristic>()?;
+    m.add_class::<PyCriticalPathAnalysis>()?;
+
+    m.add_class::<PyAttribute>()?;
+    m.add_class::<PyQuery>()?;
+    m.add_function(wrap_pyfunction!(attr, m)?)?;
 
     m.add(
         "GraphsterBaseError",
@@ -42,5 +60,21 @@ fn _pyo3_graphster(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         py.get_type_bound::<EdgeNotFoundError>(),
     )?;
     m.add("ConversionError", py.get_type_bound::<ConversionError>())?;
+    m.add(
+        "AttributeNotFoundError",
+        py.get_type_bound::<AttributeNotFoundError>(),
+    )?;
+    m.add("QueryParseError", py.get_type_bound::<QueryParseError>())?;
+    m.add("QueryTypeError", py.get_type_bound::<QueryTypeError>())?;
+    m.add(
+        "NegativeCycleError",
+        py.get_type_bound::<NegativeCycleError>(),
+    )?;
+    m.add(
+        "NegativeWeightError",
+        py.get_type_bound::<NegativeWeightError>(),
+    )?;
+    m.add("CycleError", py.get_type_bound::<CycleError>())?;
+    m.add("ConvergenceError", py.get_type_bound::<ConvergenceError>())?;
     Ok(())
 }
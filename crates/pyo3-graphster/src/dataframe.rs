@@ -0,0 +1,438 @@
+use crate::{datatypes::key::convert_attributekey_to_attributevalue, errors::PyGraphsterError};
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    errors::GraphsterError,
+    graph::{DataGraph, EdgeIndex},
+};
+use polars::prelude::{DataFrame, DataType, NamedFrom, PolarsError, Series};
+use pyo3::PyResult;
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::{
+    collections::{HashMap, HashSet},
+    mem::discriminant,
+};
+
+pub(crate) type Attributes = HashMap<AttributeKey, AttributeValue>;
+
+fn convert_polars_error(error: PolarsError) -> PyGraphsterError {
+    GraphsterError::ConversionError(error.to_string()).into()
+}
+
+macro_rules! collect_attributevalues {
+    ($chunked_array:expr, $variant:ident) => {
+        $chunked_array
+            .map_err(convert_polars_error)?
+            .into_iter()
+            .map(|value| value.map_or(AttributeValue::Null, AttributeValue::$variant))
+            .collect()
+    };
+}
+
+fn convert_series_to_attributevalues(series: &Series) -> PyResult<Vec<AttributeValue>> {
+    Ok(match series.dtype() {
+        DataType::Boolean => collect_attributevalues!(series.bool(), Boolean),
+        DataType::Float32 => collect_attributevalues!(series.f32(), Float32),
+        DataType::Float64 => collect_attributevalues!(series.f64(), Float64),
+        DataType::Int16 => collect_attributevalues!(series.i16(), Int16),
+        DataType::Int32 => collect_attributevalues!(series.i32(), Int32),
+        DataType::Int64 => collect_attributevalues!(series.i64(), Int64),
+        DataType::Int8 => collect_attributevalues!(series.i8(), Int8),
+        DataType::UInt16 => collect_attributevalues!(series.u16(), UInt16),
+        DataType::UInt32 => collect_attributevalues!(series.u32(), UInt32),
+        DataType::UInt64 => collect_attributevalues!(series.u64(), UInt64),
+        DataType::UInt8 => collect_attributevalues!(series.u8(), UInt8),
+        DataType::String => series
+            .str()
+            .map_err(convert_polars_error)?
+            .into_iter()
+            .map(|value| {
+                value.map_or(AttributeValue::Null, |value| {
+                    AttributeValue::String(value.to_string())
+                })
+            })
+            .collect(),
+        DataType::Null => vec![AttributeValue::Null; series.len()],
+        dtype => {
+            return Err(
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert column {} of dtype {} to AttributeValue",
+                    series.name(),
+                    dtype
+                )))
+                .into(),
+            )
+        }
+    })
+}
+
+fn convert_series_to_attributekeys(series: &Series) -> PyResult<Vec<AttributeKey>> {
+    Ok(convert_series_to_attributevalues(series)?
+        .into_iter()
+        .map(AttributeKey::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(PyGraphsterError::from)?)
+}
+
+fn convert_dataframe_to_attributes(
+    dataframe: &DataFrame,
+    index_columns: &[&str],
+) -> PyResult<Vec<Attributes>> {
+    let attribute_columns = dataframe
+        .get_columns()
+        .par_iter()
+        .filter(|series| !index_columns.contains(&series.name()))
+        .map(|series| -> PyResult<_> {
+            Ok((
+                AttributeKey::String(series.name().to_string()),
+                convert_series_to_attributevalues(series)?,
+            ))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut attributes: Vec<Attributes> = (0..dataframe.height())
+        .map(|_| HashMap::with_capacity(attribute_columns.len()))
+        .collect();
+
+    for (key, values) in attribute_columns {
+        for (row_attributes, value) in attributes.iter_mut().zip(values) {
+            row_attributes.insert(key.clone(), value);
+        }
+    }
+
+    Ok(attributes)
+}
+
+pub(crate) fn convert_dataframe_to_nodes(
+    nodes: &DataFrame,
+    node_index_column: &str,
+) -> PyResult<Vec<(AttributeKey, Attributes)>> {
+    let node_indices = convert_series_to_attributekeys(
+        nodes
+            .column(node_index_column)
+            .map_err(convert_polars_error)?,
+    )?;
+    let attributes = convert_dataframe_to_attributes(nodes, &[node_index_column])?;
+
+    Ok(node_indices.into_iter().zip(attributes).collect())
+}
+
+pub(crate) fn convert_dataframe_to_edges(
+    edges: &DataFrame,
+    source_column: &str,
+    target_column: &str,
+) -> PyResult<Vec<(AttributeKey, AttributeKey, Attributes)>> {
+    let source_node_indices = convert_series_to_attributekeys(
+        edges.column(source_column).map_err(convert_polars_error)?,
+    )?;
+    let target_node_indices = convert_series_to_attributekeys(
+        edges.column(target_column).map_err(convert_polars_error)?,
+    )?;
+    let attributes = convert_dataframe_to_attributes(edges, &[source_column, target_column])?;
+
+    Ok(source_node_indices
+        .into_iter()
+        .zip(target_node_indices)
+        .zip(attributes)
+        .map(|((source_node_index, target_node_index), attributes)| {
+            (source_node_index, target_node_index, attributes)
+        })
+        .collect())
+}
+
+fn convert_series_to_edgeindices(series: &Series) -> PyResult<Vec<EdgeIndex>> {
+    Ok(series
+        .cast(&DataType::UInt64)
+        .map_err(convert_polars_error)?
+        .u64()
+        .map_err(convert_polars_error)?
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| EdgeIndex::from(value as usize))
+                .ok_or_else(|| {
+                    GraphsterError::ConversionError(format!(
+                        "Could not convert column {} to EdgeIndex: values must be non-negative integers",
+                        series.name()
+                    ))
+                })
+        })
+        .collect::<Result<_, _>>()
+        .map_err(PyGraphsterError::from)?)
+}
+
+pub(crate) fn convert_dataframe_to_node_values(
+    dataframe: &DataFrame,
+    node_index_column: &str,
+    value_column: &str,
+) -> PyResult<Vec<(AttributeKey, AttributeValue)>> {
+    let node_indices = convert_series_to_attributekeys(
+        dataframe
+            .column(node_index_column)
+            .map_err(convert_polars_error)?,
+    )?;
+    let values = convert_series_to_attributevalues(
+        dataframe
+            .column(value_column)
+            .map_err(convert_polars_error)?,
+    )?;
+
+    Ok(node_indices.into_iter().zip(values).collect())
+}
+
+pub(crate) fn convert_dataframe_to_edge_values(
+    dataframe: &DataFrame,
+    edge_index_column: &str,
+    value_column: &str,
+) -> PyResult<Vec<(EdgeIndex, AttributeValue)>> {
+    let edge_indices = convert_series_to_edgeindices(
+        dataframe
+            .column(edge_index_column)
+            .map_err(convert_polars_error)?,
+    )?;
+    let values = convert_series_to_attributevalues(
+        dataframe
+            .column(value_column)
+            .map_err(convert_polars_error)?,
+    )?;
+
+    Ok(edge_indices.into_iter().zip(values).collect())
+}
+
+fn convert_attributekey_to_column_name(key: &AttributeKey) -> String {
+    match key {
+        AttributeKey::Boolean(value) => value.to_string(),
+        AttributeKey::Int128(value) => value.to_string(),
+        AttributeKey::Int16(value) => value.to_string(),
+        AttributeKey::Int32(value) => value.to_string(),
+        AttributeKey::Int64(value) => value.to_string(),
+        AttributeKey::Int8(value) => value.to_string(),
+        AttributeKey::String(value) => value.clone(),
+        AttributeKey::UInt128(value) => value.to_string(),
+        AttributeKey::UInt16(value) => value.to_string(),
+        AttributeKey::UInt32(value) => value.to_string(),
+        AttributeKey::UInt64(value) => value.to_string(),
+        AttributeKey::UInt8(value) => value.to_string(),
+        AttributeKey::Usize(value) => value.to_string(),
+    }
+}
+
+macro_rules! collect_series {
+    ($name:expr, $values:expr, $variant:ident) => {
+        Series::new(
+            $name,
+            $values
+                .into_iter()
+                .map(|value| match value {
+                    AttributeValue::$variant(value) => Some(value),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+}
+
+fn convert_attributevalues_to_series(name: &str, values: Vec<AttributeValue>) -> PyResult<Series> {
+    let Some(first_value) = values
+        .iter()
+        .find(|value| !matches!(value, AttributeValue::Null))
+    else {
+        return Ok(Series::new_null(name, values.len()));
+    };
+
+    let variant = discriminant(first_value);
+
+    if values
+        .iter()
+        .any(|value| !matches!(value, AttributeValue::Null) && discriminant(value) != variant)
+    {
+        return Err(
+            PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                "Could not convert column {} to Series: values have mixed types",
+                name
+            )))
+            .into(),
+        );
+    }
+
+    Ok(match first_value {
+        AttributeValue::Boolean(_) => collect_series!(name, values, Boolean),
+        AttributeValue::Float32(_) => collect_series!(name, values, Float32),
+        AttributeValue::Float64(_) => collect_series!(name, values, Float64),
+        AttributeValue::Int16(_) => collect_series!(name, values, Int16),
+        AttributeValue::Int32(_) => collect_series!(name, values, Int32),
+        AttributeValue::Int64(_) => collect_series!(name, values, Int64),
+        AttributeValue::Int8(_) => collect_series!(name, values, Int8),
+        AttributeValue::String(_) => collect_series!(name, values, String),
+        AttributeValue::UInt16(_) => collect_series!(name, values, UInt16),
+        AttributeValue::UInt32(_) => collect_series!(name, values, UInt32),
+        AttributeValue::UInt64(_) => collect_series!(name, values, UInt64),
+        AttributeValue::UInt8(_) => collect_series!(name, values, UInt8),
+        AttributeValue::Usize(_) => Series::new(
+            name,
+            values
+                .into_iter()
+                .map(|value| match value {
+                    AttributeValue::Usize(value) => Some(value as u64),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        ),
+        _ => {
+            return Err(
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert column {} to Series: 128-bit integers are not supported",
+                    name
+                )))
+                .into(),
+            )
+        }
+    })
+}
+
+fn column_collision_error(
+    column_name: &str,
+    key: &AttributeKey,
+    existing: Option<&AttributeKey>,
+) -> PyGraphsterError {
+    GraphsterError::ConversionError(match existing {
+        Some(existing) => format!(
+            "Could not convert attributes to DataFrame: attribute keys {:?} and {:?} both map to column {}",
+            existing, key, column_name
+        ),
+        None => format!(
+            "Could not convert attributes to DataFrame: attribute key {:?} collides with index column {}",
+            key, column_name
+        ),
+    })
+    .into()
+}
+
+fn convert_attributes_to_series(
+    attributes: &[&Attributes],
+    index_columns: &[&str],
+) -> PyResult<Vec<Series>> {
+    let keys = attributes
+        .iter()
+        .flat_map(|attributes| attributes.keys())
+        .collect::<HashSet<_>>();
+
+    let mut column_names = index_columns
+        .iter()
+        .map(|column_name| (column_name.to_string(), None))
+        .collect::<HashMap<_, _>>();
+
+    for key in &keys {
+        let column_name = convert_attributekey_to_column_name(key);
+
+        if let Some(existing) = column_names.insert(column_name.clone(), Some(*key)) {
+            return Err(column_collision_error(&column_name, key, existing).into());
+        }
+    }
+
+    let mut columns = keys
+        .into_par_iter()
+        .map(|key| {
+            let values = attributes
+                .iter()
+                .map(|attributes| attributes.get(key).cloned().unwrap_or(AttributeValue::Null))
+                .collect();
+
+            convert_attributevalues_to_series(&convert_attributekey_to_column_name(key), values)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    columns.sort_by(|left, right| left.name().cmp(right.name()));
+
+    Ok(columns)
+}
+
+pub(crate) fn convert_nodes_to_dataframe(
+    graph: &DataGraph,
+    node_index_column: &str,
+) -> PyResult<DataFrame> {
+    let (node_indices, attributes): (Vec<_>, Vec<_>) = graph
+        .node_indices()
+        .map(|node_index| -> PyResult<_> {
+            Ok((
+                convert_attributekey_to_attributevalue(node_index.0.clone()),
+                graph
+                    .node_attributes(node_index.0.clone())
+                    .map_err(PyGraphsterError::from)?,
+            ))
+        })
+        .collect::<PyResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let mut columns = vec![convert_attributevalues_to_series(
+        node_index_column,
+        node_indices,
+    )?];
+    columns.extend(convert_attributes_to_series(
+        &attributes,
+        &[node_index_column],
+    )?);
+
+    Ok(DataFrame::new(columns).map_err(convert_polars_error)?)
+}
+
+pub(crate) fn convert_edges_to_dataframe(
+    graph: &DataGraph,
+    edge_index_column: &str,
+    source_column: &str,
+    target_column: &str,
+) -> PyResult<DataFrame> {
+    let mut edge_indices = Vec::with_capacity(graph.edge_count());
+    let mut source_node_indices = Vec::with_capacity(graph.edge_count());
+    let mut target_node_indices = Vec::with_capacity(graph.edge_count());
+    let mut attributes = Vec::with_capacity(graph.edge_count());
+
+    for edge_index in graph.edge_indices() {
+        let (source_node_index, target_node_index) = graph
+            .edge_endpoints(edge_index)
+            .map_err(PyGraphsterError::from)?;
+
+        edge_indices.push(usize::from(*edge_index) as u64);
+        source_node_indices.push(convert_attributekey_to_attributevalue(
+            source_node_index.0.clone(),
+        ));
+        target_node_indices.push(convert_attributekey_to_attributevalue(
+            target_node_index.0.clone(),
+        ));
+        attributes.push(
+            graph
+                .edge_attributes(edge_index)
+                .map_err(PyGraphsterError::from)?,
+        );
+    }
+
+    let mut columns = vec![
+        Series::new(edge_index_column, edge_indices),
+        convert_attributevalues_to_series(source_column, source_node_indices)?,
+        convert_attributevalues_to_series(target_column, target_node_indices)?,
+    ];
+    columns.extend(convert_attributes_to_series(
+        &attributes,
+        &[edge_index_column, source_column, target_column],
+    )?);
+
+    Ok(DataFrame::new(columns).map_err(convert_polars_error)?)
+}
+
+pub(crate) fn convert_node_values_to_dataframe(
+    values: Vec<(AttributeKey, f64)>,
+    node_index_column: &str,
+    value_column: &str,
+) -> PyResult<DataFrame> {
+    let (node_indices, values): (Vec<_>, Vec<_>) = values
+        .into_iter()
+        .map(|(node_index, value)| (convert_attributekey_to_attributevalue(node_index), value))
+        .unzip();
+
+    let columns = vec![
+        convert_attributevalues_to_series(node_index_column, node_indices)?,
+        Series::new(value_column, values),
+    ];
+
+    Ok(DataFrame::new(columns).map_err(convert_polars_error)?)
+}
@@ -1,9 +1,13 @@
 use super::{
-    value::convert_pyobject_to_attributevalue, PyBoolean, PyInt128, PyInt16, PyInt32, PyInt64,
-    PyInt8, PyString, PyUInt128, PyUInt16, PyUInt32, PyUInt64, PyUInt8, PyUsize,
+    value::convert_pyobject_to_attributevalue, IntoPyNative, PyBoolean, PyInt128, PyInt16, PyInt32,
+    PyInt64, PyInt8, PyString, PyUInt128, PyUInt16, PyUInt32, PyUInt64, PyUInt8, PyUsize,
 };
 use crate::errors::PyGraphsterError;
-use graphster::{datatypes::AttributeKey, from_marker::FromMarker, implement_from_marker};
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    from_marker::FromMarker,
+    implement_from_marker,
+};
 use pyo3::{Bound, FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -24,6 +28,24 @@ impl From<PyAttributeKey> for AttributeKey {
 
 implement_from_marker!(AttributeKey, PyAttributeKey);
 
+pub(crate) fn convert_attributekey_to_attributevalue(key: AttributeKey) -> AttributeValue {
+    match key {
+        AttributeKey::Boolean(value) => AttributeValue::Boolean(value),
+        AttributeKey::Int128(value) => AttributeValue::Int128(value),
+        AttributeKey::Int16(value) => AttributeValue::Int16(value),
+        AttributeKey::Int32(value) => AttributeValue::Int32(value),
+        AttributeKey::Int64(value) => AttributeValue::Int64(value),
+        AttributeKey::Int8(value) => AttributeValue::Int8(value),
+        AttributeKey::String(value) => AttributeValue::String(value),
+        AttributeKey::UInt128(value) => AttributeValue::UInt128(value),
+        AttributeKey::UInt16(value) => AttributeValue::UInt16(value),
+        AttributeKey::UInt32(value) => AttributeValue::UInt32(value),
+        AttributeKey::UInt64(value) => AttributeValue::UInt64(value),
+        AttributeKey::UInt8(value) => AttributeValue::UInt8(value),
+        AttributeKey::Usize(value) => AttributeValue::Usize(value),
+    }
+}
+
 impl<'a> FromPyObject<'a> for PyAttributeKey {
     fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
         Ok(
@@ -53,3 +75,19 @@ impl IntoPy<PyObject> for PyAttributeKey {
         }
     }
 }
+
+impl IntoPyNative for PyAttributeKey {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        match self.0 {
+            AttributeKey::Boolean(value) => value.into_py(py),
+            AttributeKey::Int64(value) => value.into_py(py),
+            AttributeKey::String(value) => value.into_py(py),
+            AttributeKey::UInt64(value) if value > i64::MAX as u64 => value.into_py(py),
+            AttributeKey::Int128(value) if value < i64::MIN as i128 || value > u64::MAX as i128 => {
+                value.into_py(py)
+            }
+            AttributeKey::UInt128(value) if value > i128::MAX as u128 => value.into_py(py),
+            key => Self(key).into_py(py),
+        }
+    }
+}
@@ -1,7 +1,96 @@
-use pyo3::{pyclass, pymethods};
+use graphster::datatypes::AttributeValue;
+use pyo3::{
+    basic::CompareOp,
+    exceptions::PyTypeError,
+    pyclass, pymethods,
+    types::{PyAnyMethods, PyDict, PyDictMethods, PyList, PyTuple},
+    Bound, FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python,
+};
+use std::{collections::HashMap, hash::Hash, mem::discriminant};
+use value::{convert_attributevalue_to_native, convert_pyobject_to_attributevalue};
+
+pub(crate) trait IntoPyNative: IntoPy<PyObject> {
+    fn into_py_native(self, py: Python<'_>) -> PyObject;
+
+    fn into_py_with_mode(self, py: Python<'_>, native_types: bool) -> PyObject
+    where
+        Self: Sized,
+    {
+        if native_types {
+            self.into_py_native(py)
+        } else {
+            self.into_py(py)
+        }
+    }
+}
+
+macro_rules! implement_into_py_native {
+    ($($type:ty),*) => {
+        $(
+            impl IntoPyNative for $type {
+                fn into_py_native(self, py: Python<'_>) -> PyObject {
+                    self.into_py(py)
+                }
+            }
+        )*
+    };
+}
+
+implement_into_py_native!(bool, f64, usize);
+
+impl<T: IntoPyNative> IntoPyNative for Vec<T> {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        PyList::new_bound(
+            py,
+            self.into_iter().map(|element| element.into_py_native(py)),
+        )
+        .into()
+    }
+}
+
+impl<T: IntoPyNative> IntoPyNative for Option<T> {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        match self {
+            Some(value) => value.into_py_native(py),
+            None => py.None(),
+        }
+    }
+}
+
+impl<K: IntoPyNative + Hash + Eq, V: IntoPyNative> IntoPyNative for HashMap<K, V> {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new_bound(py);
+
+        for (key, value) in self {
+            dict.set_item(key.into_py_native(py), value.into_py_native(py))
+                .expect("Setting item must succeed");
+        }
+
+        dict.into()
+    }
+}
+
+impl<A: IntoPyNative, B: IntoPyNative> IntoPyNative for (A, B) {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        (self.0.into_py_native(py), self.1.into_py_native(py)).into_py(py)
+    }
+}
+
+pub(crate) fn extract_iterable<'py, T: FromPyObject<'py>>(
+    iterable: &Bound<'py, PyAny>,
+) -> PyResult<Vec<T>> {
+    if iterable.is_instance_of::<pyo3::types::PyString>() {
+        return Err(PyTypeError::new_err(
+            "Expected an iterable of indices, not str",
+        ));
+    }
+
+    iterable.iter()?.map(|item| item?.extract()).collect()
+}
 
 macro_rules! implement_pymethods {
-    ($struct:ty, $inner:ty) => {
+    ($struct:ident, $inner:ty, $variant:ident, { $($methods:tt)* }) => {
+        #[allow(clippy::clone_on_copy, clippy::unnecessary_cast)]
         #[pymethods]
         impl $struct {
             #[new]
@@ -10,12 +99,105 @@ macro_rules! implement_pymethods {
             }
 
             pub fn value(&self) -> $inner {
-                self.0
+                self.0.clone()
+            }
+
+            fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+                Ok(format!(
+                    "{}({})",
+                    stringify!($struct),
+                    self.value().into_py(py).bind(py).repr()?
+                ))
+            }
+
+            fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+                Ok(self.value().into_py(py).bind(py).str()?.to_string())
             }
+
+            fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+                let value = AttributeValue::$variant(self.value());
+                let native = self.value().into_py(py);
+
+                if convert_pyobject_to_attributevalue(native.bind(py))
+                    .is_ok_and(|native_value| discriminant(&native_value) == discriminant(&value))
+                {
+                    return native.bind(py).hash();
+                }
+
+                PyTuple::new_bound(py, [stringify!($variant).into_py(py), native]).hash()
+            }
+
+            fn __bool__(&self, py: Python<'_>) -> PyResult<bool> {
+                self.value().into_py(py).bind(py).is_truthy()
+            }
+
+            fn __richcmp__(
+                &self,
+                other: &Bound<'_, PyAny>,
+                op: CompareOp,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let Ok(other) = convert_pyobject_to_attributevalue(other) else {
+                    return Ok(py.NotImplemented());
+                };
+                let value = AttributeValue::$variant(self.value());
+
+                if discriminant(&value) != discriminant(&other) {
+                    match op {
+                        CompareOp::Eq => return Ok(false.into_py(py)),
+                        CompareOp::Ne => return Ok(true.into_py(py)),
+                        _ => {}
+                    }
+                }
+
+                Ok(convert_attributevalue_to_native(value, py)
+                    .bind(py)
+                    .rich_compare(convert_attributevalue_to_native(other, py), op)?
+                    .unbind())
+            }
+
+            $($methods)*
         }
     };
 }
 
+macro_rules! implement_integer_pymethods {
+    ($struct:ident, $inner:ty, $variant:ident) => {
+        implement_pymethods!($struct, $inner, $variant, {
+            fn __int__(&self) -> $inner {
+                self.0
+            }
+
+            fn __index__(&self) -> $inner {
+                self.0
+            }
+
+            fn __float__(&self) -> f64 {
+                self.0 as f64
+            }
+        });
+    };
+}
+
+macro_rules! implement_float_pymethods {
+    ($struct:ident, $inner:ty, $variant:ident) => {
+        implement_pymethods!($struct, $inner, $variant, {
+            fn __float__(&self) -> f64 {
+                self.0 as f64
+            }
+
+            fn __int__(&self, py: Python<'_>) -> PyResult<PyObject> {
+                Ok(self
+                    .value()
+                    .into_py(py)
+                    .bind(py)
+                    .call_method0("__int__")?
+                    .unbind())
+            }
+        });
+    };
+}
+
 pub(crate) mod key;
 pub(crate) mod value;
 
@@ -23,98 +205,104 @@ pub(crate) mod value;
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyBoolean(bool);
-implement_pymethods!(PyBoolean, bool);
+implement_pymethods!(PyBoolean, bool, Boolean, {
+    fn __int__(&self) -> u8 {
+        self.0.into()
+    }
+
+    fn __index__(&self) -> u8 {
+        self.0.into()
+    }
+
+    fn __float__(&self) -> f64 {
+        f64::from(u8::from(self.0))
+    }
+});
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyFloat32(f32);
-implement_pymethods!(PyFloat32, f32);
+implement_float_pymethods!(PyFloat32, f32, Float32);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyFloat64(f64);
-implement_pymethods!(PyFloat64, f64);
+implement_float_pymethods!(PyFloat64, f64, Float64);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyInt128(i128);
-implement_pymethods!(PyInt128, i128);
+implement_integer_pymethods!(PyInt128, i128, Int128);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyInt16(i16);
-implement_pymethods!(PyInt16, i16);
+implement_integer_pymethods!(PyInt16, i16, Int16);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyInt32(i32);
-implement_pymethods!(PyInt32, i32);
+implement_integer_pymethods!(PyInt32, i32, Int32);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyInt64(i64);
-implement_pymethods!(PyInt64, i64);
+implement_integer_pymethods!(PyInt64, i64, Int64);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyInt8(i8);
-implement_pymethods!(PyInt8, i8);
+implement_integer_pymethods!(PyInt8, i8, Int8);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyString(String);
-#[pymethods]
-impl PyString {
-    #[new]
-    pub fn new(value: String) -> Self {
-        Self(value)
-    }
-
-    pub fn value(&self) -> String {
-        self.0.clone()
+implement_pymethods!(PyString, String, String, {
+    fn __len__(&self) -> usize {
+        self.0.chars().count()
     }
-}
+});
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUInt128(u128);
-implement_pymethods!(PyUInt128, u128);
+implement_integer_pymethods!(PyUInt128, u128, UInt128);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUInt16(u16);
-implement_pymethods!(PyUInt16, u16);
+implement_integer_pymethods!(PyUInt16, u16, UInt16);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUInt32(u32);
-implement_pymethods!(PyUInt32, u32);
+implement_integer_pymethods!(PyUInt32, u32, UInt32);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUInt64(u64);
-implement_pymethods!(PyUInt64, u64);
+implement_integer_pymethods!(PyUInt64, u64, UInt64);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUInt8(u8);
-implement_pymethods!(PyUInt8, u8);
+implement_integer_pymethods!(PyUInt8, u8, UInt8);
 
 #[pyclass]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PyUsize(usize);
-implement_pymethods!(PyUsize, usize);
+implement_integer_pymethods!(PyUsize, usize, Usize);
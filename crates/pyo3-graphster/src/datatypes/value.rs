@@ -1,7 +1,7 @@
 use crate::{
     datatypes::{
-        PyBoolean, PyFloat32, PyFloat64, PyInt128, PyInt16, PyInt32, PyInt64, PyInt8, PyUInt128,
-        PyUInt16, PyUInt32, PyUInt64, PyUInt8, PyUsize,
+        IntoPyNative, PyBoolean, PyFloat32, PyFloat64, PyInt128, PyInt16, PyInt32, PyInt64, PyInt8,
+        PyUInt128, PyUInt16, PyUInt32, PyUInt64, PyUInt8, PyUsize,
     },
     gil_hash_map::GILHashMap,
     Lut, PyGraphsterError,
@@ -40,9 +40,18 @@ pub(crate) fn convert_pyobject_to_attributevalue(
     }
 
     fn convert_int(ob: &Bound<'_, PyAny>) -> PyResult<AttributeValue> {
-        Ok(AttributeValue::Int64(
-            ob.extract().expect("Extraction must succeed"),
-        ))
+        ob.extract()
+            .map(AttributeValue::Int64)
+            .or_else(|_| ob.extract().map(AttributeValue::UInt64))
+            .or_else(|_| ob.extract().map(AttributeValue::Int128))
+            .or_else(|_| ob.extract().map(AttributeValue::UInt128))
+            .map_err(|_| {
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert {} to AttributeValue: integer does not fit into 128 bits",
+                    ob
+                )))
+                .into()
+            })
     }
 
     fn convert_string(ob: &Bound<'_, PyAny>) -> PyResult<AttributeValue> {
@@ -246,6 +255,27 @@ pub(crate) fn convert_pyobject_to_attributevalue(
     })
 }
 
+pub(crate) fn convert_attributevalue_to_native(value: AttributeValue, py: Python<'_>) -> PyObject {
+    match value {
+        AttributeValue::Boolean(value) => value.into_py(py),
+        AttributeValue::Float32(value) => value.into_py(py),
+        AttributeValue::Float64(value) => value.into_py(py),
+        AttributeValue::Int128(value) => value.into_py(py),
+        AttributeValue::Int16(value) => value.into_py(py),
+        AttributeValue::Int32(value) => value.into_py(py),
+        AttributeValue::Int64(value) => value.into_py(py),
+        AttributeValue::Int8(value) => value.into_py(py),
+        AttributeValue::Null => py.None(),
+        AttributeValue::String(value) => value.into_py(py),
+        AttributeValue::UInt128(value) => value.into_py(py),
+        AttributeValue::UInt16(value) => value.into_py(py),
+        AttributeValue::UInt32(value) => value.into_py(py),
+        AttributeValue::UInt64(value) => value.into_py(py),
+        AttributeValue::UInt8(value) => value.into_py(py),
+        AttributeValue::Usize(value) => value.into_py(py),
+    }
+}
+
 impl<'a> FromPyObject<'a> for PyAttributeValue {
     fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
         convert_pyobject_to_attributevalue(ob).map(PyAttributeValue::from)
@@ -274,3 +304,25 @@ impl IntoPy<PyObject> for PyAttributeValue {
         }
     }
 }
+
+impl IntoPyNative for PyAttributeValue {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        let lossless = match self.0 {
+            AttributeValue::Boolean(_)
+            | AttributeValue::Float64(_)
+            | AttributeValue::Int64(_)
+            | AttributeValue::Null
+            | AttributeValue::String(_) => true,
+            AttributeValue::UInt64(value) => value > i64::MAX as u64,
+            AttributeValue::Int128(value) => value < i64::MIN as i128 || value > u64::MAX as i128,
+            AttributeValue::UInt128(value) => value > i128::MAX as u128,
+            _ => false,
+        };
+
+        if lossless {
+            convert_attributevalue_to_native(self.0, py)
+        } else {
+            self.into_py(py)
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use graphster::errors::GraphsterError;
-use pyo3::{create_exception, exceptions::PyException, PyErr};
+use pyo3::{create_exception, exceptions::PyException, types::PyAnyMethods, PyErr, Python};
 
 create_exception!(_graphster.exceptions, GraphsterBaseError, PyException);
 create_exception!(_graphster.exceptions, NodeNotFoundError, GraphsterBaseError);
@@ -10,26 +10,82 @@ create_exception!(
 );
 create_exception!(_graphster.exceptions, EdgeNotFoundError, GraphsterBaseError);
 create_exception!(_graphster.exceptions, ConversionError, GraphsterBaseError);
+create_exception!(
+    _graphster.exceptions,
+    AttributeNotFoundError,
+    GraphsterBaseError
+);
+create_exception!(_graphster.exceptions, QueryParseError, GraphsterBaseError);
+create_exception!(_graphster.exceptions, QueryTypeError, GraphsterBaseError);
+create_exception!(
+    _graphster.exceptions,
+    NegativeCycleError,
+    GraphsterBaseError
+);
+create_exception!(
+    _graphster.exceptions,
+    NegativeWeightError,
+    GraphsterBaseError
+);
+create_exception!(_graphster.exceptions, CycleError, GraphsterBaseError);
+create_exception!(_graphster.exceptions, ConvergenceError, GraphsterBaseError);
 
 #[derive(Debug)]
-#[repr(transparent)]
-pub struct PyGraphsterError(GraphsterError);
+pub enum PyGraphsterError {
+    Graphster(GraphsterError),
+    NodeNotFound(String),
+    EdgeNotFound(String),
+    AttributeNotFound(String),
+    QueryParse { message: String, position: usize },
+    QueryType(String),
+    NegativeCycle(String),
+    NegativeWeight(String),
+    Cycle(String),
+    Convergence(String),
+}
 
 impl From<GraphsterError> for PyGraphsterError {
     fn from(value: GraphsterError) -> Self {
-        Self(value)
+        Self::Graphster(value)
     }
 }
 
 impl From<PyGraphsterError> for PyErr {
     fn from(value: PyGraphsterError) -> Self {
-        match value.0 {
-            GraphsterError::NodeNotFound { .. } => NodeNotFoundError::new_err(value.0.to_string()),
-            GraphsterError::NodeAlreadyExists { .. } => {
-                NodeAlreadyExistsError::new_err(value.0.to_string())
+        match value {
+            PyGraphsterError::Graphster(error) => match error {
+                GraphsterError::NodeNotFound { .. } => {
+                    NodeNotFoundError::new_err(error.to_string())
+                }
+                GraphsterError::NodeAlreadyExists { .. } => {
+                    NodeAlreadyExistsError::new_err(error.to_string())
+                }
+                GraphsterError::EdgeNotFound { .. } => {
+                    EdgeNotFoundError::new_err(error.to_string())
+                }
+                GraphsterError::ConversionError { .. } => {
+                    ConversionError::new_err(error.to_string())
+                }
+            },
+            PyGraphsterError::NodeNotFound(message) => NodeNotFoundError::new_err(message),
+            PyGraphsterError::EdgeNotFound(message) => EdgeNotFoundError::new_err(message),
+            PyGraphsterError::AttributeNotFound(message) => {
+                AttributeNotFoundError::new_err(message)
             }
-            GraphsterError::EdgeNotFound { .. } => EdgeNotFoundError::new_err(value.0.to_string()),
-            GraphsterError::ConversionError { .. } => ConversionError::new_err(value.0.to_string()),
+            PyGraphsterError::QueryParse { message, position } => Python::with_gil(|py| {
+                let error =
+                    QueryParseError::new_err(format!("{} at position {}", message, position));
+
+                match error.value_bound(py).setattr("position", position) {
+                    Ok(()) => error,
+                    Err(error) => error,
+                }
+            }),
+            PyGraphsterError::QueryType(message) => QueryTypeError::new_err(message),
+            PyGraphsterError::NegativeCycle(message) => NegativeCycleError::new_err(message),
+            PyGraphsterError::NegativeWeight(message) => NegativeWeightError::new_err(message),
+            PyGraphsterError::Cycle(message) => CycleError::new_err(message),
+            PyGraphsterError::Convergence(message) => ConvergenceError::new_err(message),
         }
     }
 }
@@ -0,0 +1,276 @@
+use super::topology::{node_not_found_error, GraphView};
+use crate::{errors::PyGraphsterError, query::predicate::convert_attributevalue_to_f64};
+use graphster::{datatypes::AttributeKey, errors::GraphsterError, graph::EdgeIndex};
+use pyo3::{types::PyAnyMethods, Bound, FromPyObject, PyAny, PyResult};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WeightSource {
+    Edge,
+    Node,
+}
+
+impl<'a> FromPyObject<'a> for WeightSource {
+    fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
+        match ob.extract::<String>().as_deref() {
+            Ok("edge") => Ok(Self::Edge),
+            Ok("node") => Ok(Self::Node),
+            _ => Err(
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert {} to WeightSource: value must be one of 'edge' or 'node'",
+                    ob
+                )))
+                .into(),
+            ),
+        }
+    }
+}
+
+pub(crate) fn node_weight(
+    view: &GraphView<'_>,
+    node_index: &AttributeKey,
+    weight: &AttributeKey,
+) -> Result<f64, PyGraphsterError> {
+    let value = view
+        .node_attributes(node_index)?
+        .get(weight)
+        .ok_or_else(|| {
+            PyGraphsterError::AttributeNotFound(format!(
+                "Weight attribute {} not found on node {}",
+                weight, node_index
+            ))
+        })?;
+
+    convert_attributevalue_to_f64(value)
+        .filter(|value| !value.is_nan())
+        .ok_or_else(|| {
+            PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                "Could not convert weight attribute {} of node {} to a number: {:?}",
+                weight, node_index, value
+            )))
+        })
+}
+
+pub(crate) fn edge_weight(
+    view: &GraphView<'_>,
+    edge_index: &EdgeIndex,
+    weight: Option<&AttributeKey>,
+) -> Result<f64, PyGraphsterError> {
+    let Some(weight) = weight else {
+        return Ok(1.0);
+    };
+
+    let value = view
+        .edge_attributes(edge_index)?
+        .get(weight)
+        .ok_or_else(|| {
+            PyGraphsterError::AttributeNotFound(format!(
+                "Weight attribute {} not found on edge {}",
+                weight,
+                usize::from(*edge_index)
+            ))
+        })?;
+
+    convert_attributevalue_to_f64(value)
+        .filter(|value| !value.is_nan())
+        .ok_or_else(|| {
+            PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                "Could not convert weight attribute {} of edge {} to a number: {:?}",
+                weight,
+                usize::from(*edge_index),
+                value
+            )))
+        })
+}
+
+#[derive(Debug)]
+pub(crate) struct Adjacency {
+    pub(crate) node_indices: Vec<AttributeKey>,
+    positions: HashMap<AttributeKey, usize>,
+    pub(crate) outgoing: Vec<Vec<(usize, f64)>>,
+    pub(crate) edge_indices: Vec<Vec<EdgeIndex>>,
+}
+
+impl Adjacency {
+    pub(crate) fn new(
+        view: &GraphView<'_>,
+        weight: Option<&AttributeKey>,
+    ) -> Result<Self, PyGraphsterError> {
+        let node_indices = view.node_indices();
+        let positions = node_indices
+            .iter()
+            .enumerate()
+            .map(|(position, node_index)| (node_index.clone(), position))
+            .collect::<HashMap<_, _>>();
+
+        let (outgoing, edge_indices) = node_indices
+            .par_iter()
+            .map(|node_index| {
+                Ok(view
+                    .outgoing_edges(node_index)?
+                    .into_iter()
+                    .map(|(edge_index, neighbor_index)| {
+                        Ok((
+                            (
+                                positions[neighbor_index],
+                                edge_weight(view, &edge_index, weight)?,
+                            ),
+                            edge_index,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, PyGraphsterError>>()?
+                    .into_iter()
+                    .unzip())
+            })
+            .collect::<Result<Vec<(Vec<_>, Vec<_>)>, PyGraphsterError>>()?
+            .into_iter()
+            .unzip();
+
+        Ok(Self {
+            node_indices,
+            positions,
+            outgoing,
+            edge_indices,
+        })
+    }
+
+    pub(crate) fn reversed(&self) -> Self {
+        let mut outgoing = vec![Vec::new(); self.node_count()];
+        let mut edge_indices = vec![Vec::new(); self.node_count()];
+
+        for (position, edges) in self.outgoing.iter().enumerate() {
+            for (&(neighbor, weight), &edge_index) in edges.iter().zip(&self.edge_indices[position])
+            {
+                outgoing[neighbor].push((position, weight));
+                edge_indices[neighbor].push(edge_index);
+            }
+        }
+
+        Self {
+            node_indices: self.node_indices.clone(),
+            positions: self.positions.clone(),
+            outgoing,
+            edge_indices,
+        }
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.node_indices.len()
+    }
+
+    pub(crate) fn position(&self, node_index: &AttributeKey) -> Result<usize, PyGraphsterError> {
+        self.positions
+            .get(node_index)
+            .copied()
+            .ok_or_else(|| node_not_found_error(node_index))
+    }
+
+    pub(crate) fn has_negative_weights(&self) -> bool {
+        self.outgoing
+            .par_iter()
+            .any(|edges| edges.iter().any(|(_, weight)| *weight < 0.0))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct LazyAdjacency<'a> {
+    view: &'a GraphView<'a>,
+    weight: Option<&'a AttributeKey>,
+    pub(crate) node_indices: Vec<AttributeKey>,
+    positions: HashMap<AttributeKey, usize>,
+    outgoing: Vec<Option<Vec<(usize, f64)>>>,
+}
+
+impl<'a> LazyAdjacency<'a> {
+    pub(crate) fn new(view: &'a GraphView<'a>, weight: Option<&'a AttributeKey>) -> Self {
+        Self {
+            view,
+            weight,
+            node_indices: Vec::new(),
+            positions: HashMap::new(),
+            outgoing: Vec::new(),
+        }
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.node_indices.len()
+    }
+
+    fn push(&mut self, node_index: &AttributeKey) -> usize {
+        if let Some(&position) = self.positions.get(node_index) {
+            return position;
+        }
+
+        self.node_indices.push(node_index.clone());
+        self.positions
+            .insert(node_index.clone(), self.node_indices.len() - 1);
+        self.outgoing.push(None);
+
+        self.node_indices.len() - 1
+    }
+
+    pub(crate) fn position(
+        &mut self,
+        node_index: &AttributeKey,
+    ) -> Result<usize, PyGraphsterError> {
+        self.view.node_attributes(node_index)?;
+
+        Ok(self.push(node_index))
+    }
+
+    pub(crate) fn outgoing(
+        &mut self,
+        position: usize,
+    ) -> Result<&[(usize, f64)], PyGraphsterError> {
+        if self.outgoing[position].is_none() {
+            let view = self.view;
+            let mut edges = Vec::new();
+
+            for (edge_index, neighbor_index) in view.outgoing_edges(&self.node_indices[position])? {
+                let weight = edge_weight(view, &edge_index, self.weight)?;
+
+                edges.push((self.push(neighbor_index), weight));
+            }
+
+            self.outgoing[position] = Some(edges);
+        }
+
+        Ok(self.expanded(position))
+    }
+
+    pub(crate) fn expanded(&self, position: usize) -> &[(usize, f64)] {
+        self.outgoing[position].as_deref().unwrap_or_default()
+    }
+
+    pub(crate) fn reaches_negative_weight(
+        &mut self,
+        source: usize,
+    ) -> Result<bool, PyGraphsterError> {
+        if self.weight.is_none() {
+            return Ok(false);
+        }
+
+        let mut visited = vec![false; self.node_count()];
+        let mut stack = vec![source];
+        let mut negative = false;
+        visited[source] = true;
+
+        while let Some(position) = stack.pop() {
+            for &(neighbor, weight) in self.outgoing(position)? {
+                negative |= weight < 0.0;
+
+                if neighbor >= visited.len() {
+                    visited.resize(neighbor + 1, false);
+                }
+
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        Ok(negative)
+    }
+}
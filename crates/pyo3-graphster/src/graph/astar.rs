@@ -0,0 +1,265 @@
+use super::{
+    adjacency::LazyAdjacency,
+    shortest_paths::{HeapEntry, ShortestPathTree},
+    topology::GraphView,
+    PyNodeIndex,
+};
+use crate::{
+    datatypes::{key::PyAttributeKey, IntoPyNative},
+    errors::PyGraphsterError,
+    query::predicate::convert_attributevalue_to_f64,
+};
+use graphster::{datatypes::AttributeKey, errors::GraphsterError};
+use pyo3::{
+    exceptions::PyTypeError, pyclass, pymethods, types::PyAnyMethods, Bound, FromPyObject, PyAny,
+    PyErr, PyObject, PyResult, Python,
+};
+use std::collections::BinaryHeap;
+
+const EARTH_RADIUS_KILOMETERS: f64 = 6371.0088;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Heuristic {
+    Euclidean {
+        x: AttributeKey,
+        y: AttributeKey,
+    },
+    Haversine {
+        latitude: AttributeKey,
+        longitude: AttributeKey,
+        radius: f64,
+    },
+}
+
+fn node_coordinate(
+    view: &GraphView<'_>,
+    node_index: &AttributeKey,
+    key: &AttributeKey,
+) -> Result<f64, PyGraphsterError> {
+    let value = view.node_attributes(node_index)?.get(key).ok_or_else(|| {
+        PyGraphsterError::AttributeNotFound(format!(
+            "Coordinate attribute {} not found on node {}",
+            key, node_index
+        ))
+    })?;
+
+    convert_attributevalue_to_f64(value).ok_or_else(|| {
+        PyGraphsterError::from(GraphsterError::ConversionError(format!(
+            "Could not convert coordinate attribute {} of node {} to a number: {:?}",
+            key, node_index, value
+        )))
+    })
+}
+
+impl Heuristic {
+    fn coordinates(
+        &self,
+        view: &GraphView<'_>,
+        node_index: &AttributeKey,
+    ) -> Result<(f64, f64), PyGraphsterError> {
+        let (first, second) = match self {
+            Heuristic::Euclidean { x, y } => (x, y),
+            Heuristic::Haversine {
+                latitude,
+                longitude,
+                ..
+            } => (latitude, longitude),
+        };
+
+        Ok((
+            node_coordinate(view, node_index, first)?,
+            node_coordinate(view, node_index, second)?,
+        ))
+    }
+
+    fn distance(&self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        match self {
+            Heuristic::Euclidean { .. } => (from.0 - to.0).hypot(from.1 - to.1),
+            Heuristic::Haversine { radius, .. } => {
+                let (from_latitude, to_latitude) = (from.0.to_radians(), to.0.to_radians());
+                let latitude_delta = to_latitude - from_latitude;
+                let longitude_delta = (to.1 - from.1).to_radians();
+                let haversine = (latitude_delta / 2.0).sin().powi(2)
+                    + from_latitude.cos()
+                        * to_latitude.cos()
+                        * (longitude_delta / 2.0).sin().powi(2);
+
+                2.0 * radius * haversine.sqrt().min(1.0).asin()
+            }
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct PyHeuristic(pub(crate) Heuristic);
+
+#[pymethods]
+impl PyHeuristic {
+    #[staticmethod]
+    fn euclidean(x: PyAttributeKey, y: PyAttributeKey) -> PyHeuristic {
+        PyHeuristic(Heuristic::Euclidean {
+            x: x.into(),
+            y: y.into(),
+        })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (latitude, longitude, radius = EARTH_RADIUS_KILOMETERS))]
+    fn haversine(latitude: PyAttributeKey, longitude: PyAttributeKey, radius: f64) -> PyHeuristic {
+        PyHeuristic(Heuristic::Haversine {
+            latitude: latitude.into(),
+            longitude: longitude.into(),
+            radius,
+        })
+    }
+}
+
+pub(crate) enum PyHeuristicInput {
+    Builtin(PyHeuristic),
+    Callable(PyObject),
+}
+
+impl<'a> FromPyObject<'a> for PyHeuristicInput {
+    fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
+        if let Ok(heuristic) = ob.extract::<PyHeuristic>() {
+            return Ok(Self::Builtin(heuristic));
+        }
+
+        if !ob.is_callable() {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a PyHeuristic or a callable taking (node_index, target), got {}",
+                ob.get_type()
+            )));
+        }
+
+        Ok(Self::Callable(ob.clone().unbind()))
+    }
+}
+
+pub(crate) fn astar_path<E: From<PyGraphsterError>>(
+    view: &GraphView<'_>,
+    source: &AttributeKey,
+    target: &AttributeKey,
+    weight: Option<&AttributeKey>,
+    mut heuristic: impl FnMut(&AttributeKey) -> Result<f64, E>,
+) -> Result<Option<Vec<AttributeKey>>, E> {
+    let mut adjacency = LazyAdjacency::new(view, weight);
+    let source = adjacency.position(source)?;
+    let target = adjacency.position(target)?;
+
+    let mut estimates = vec![None; adjacency.node_count()];
+    let mut estimate = |adjacency: &LazyAdjacency<'_>, position: usize| -> Result<f64, E> {
+        if position >= estimates.len() {
+            estimates.resize(adjacency.node_count(), None);
+        }
+
+        if let Some(estimate) = estimates[position] {
+            return Ok(estimate);
+        }
+
+        let value = heuristic(&adjacency.node_indices[position])?;
+        estimates[position] = Some(value);
+
+        Ok(value)
+    };
+
+    let mut tree = ShortestPathTree::new(adjacency.node_count(), source);
+    let mut heap = BinaryHeap::from([HeapEntry {
+        distance: estimate(&adjacency, source)?,
+        position: source,
+    }]);
+
+    while let Some(HeapEntry { distance, position }) = heap.pop() {
+        if position == target {
+            break;
+        }
+
+        let Some(current) = tree.distances[position] else {
+            continue;
+        };
+
+        if distance > current + estimate(&adjacency, position)? {
+            continue;
+        }
+
+        adjacency.outgoing(position)?;
+        tree.grow(adjacency.node_count());
+
+        for &(neighbor, weight) in adjacency.expanded(position) {
+            if weight < 0.0 {
+                return Err(PyGraphsterError::NegativeWeight(
+                    "A* search requires non-negative edge weights".to_string(),
+                )
+                .into());
+            }
+
+            let candidate = current + weight;
+
+            if tree.distances[neighbor].is_none_or(|existing| candidate < existing) {
+                tree.distances[neighbor] = Some(candidate);
+                tree.predecessors[neighbor] = Some(position);
+                heap.push(HeapEntry {
+                    distance: candidate + estimate(&adjacency, neighbor)?,
+                    position: neighbor,
+                });
+            }
+        }
+    }
+
+    Ok(tree.path(target).map(|path| {
+        path.into_iter()
+            .map(|position| adjacency.node_indices[position].clone())
+            .collect()
+    }))
+}
+
+pub(crate) fn builtin_astar_path(
+    view: &GraphView<'_>,
+    source: &AttributeKey,
+    target: &AttributeKey,
+    weight: Option<&AttributeKey>,
+    heuristic: &Heuristic,
+) -> Result<Option<Vec<AttributeKey>>, PyGraphsterError> {
+    let target_coordinates = heuristic.coordinates(view, target)?;
+
+    astar_path(view, source, target, weight, |node_index| {
+        Ok(heuristic.distance(heuristic.coordinates(view, node_index)?, target_coordinates))
+    })
+}
+
+impl PyHeuristicInput {
+    pub(crate) fn astar_path(
+        &self,
+        view: &GraphView<'_>,
+        source: &AttributeKey,
+        target: &AttributeKey,
+        weight: Option<&AttributeKey>,
+        native_types: bool,
+        py: Python<'_>,
+    ) -> PyResult<Option<Vec<AttributeKey>>> {
+        match self {
+            PyHeuristicInput::Builtin(heuristic) => Ok(py.allow_threads(|| {
+                builtin_astar_path(view, source, target, weight, &heuristic.0)
+            })?),
+            PyHeuristicInput::Callable(heuristic) => {
+                let target_index =
+                    PyNodeIndex::from(target.clone()).into_py_with_mode(py, native_types);
+
+                astar_path::<PyErr>(view, source, target, weight, |node_index| {
+                    heuristic
+                        .call1(
+                            py,
+                            (
+                                PyNodeIndex::from(node_index.clone())
+                                    .into_py_with_mode(py, native_types),
+                                target_index.clone_ref(py),
+                            ),
+                        )?
+                        .extract(py)
+                })
+            }
+        }
+    }
+}
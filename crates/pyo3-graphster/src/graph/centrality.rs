@@ -0,0 +1,811 @@
+use super::{
+    adjacency::Adjacency,
+    neighbors::Direction,
+    shortest_paths::{dijkstra, HeapEntry},
+    topology::GraphView,
+};
+use crate::errors::PyGraphsterError;
+use graphster::{datatypes::AttributeKey, errors::GraphsterError, graph::EdgeIndex};
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
+};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+fn convergence_error(algorithm: &str, max_iter: usize) -> PyGraphsterError {
+    PyGraphsterError::Convergence(format!(
+        "{} failed to converge within {} iterations",
+        algorithm, max_iter
+    ))
+}
+
+fn checked_adjacency(
+    view: &GraphView<'_>,
+    weight: Option<&AttributeKey>,
+    algorithm: &str,
+) -> Result<Adjacency, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, weight)?;
+
+    if adjacency.has_negative_weights() {
+        return Err(PyGraphsterError::NegativeWeight(format!(
+            "{} requires non-negative edge weights",
+            algorithm
+        )));
+    }
+
+    Ok(adjacency)
+}
+
+fn node_values(adjacency: &Adjacency, values: Vec<f64>) -> Vec<(AttributeKey, f64)> {
+    adjacency.node_indices.iter().cloned().zip(values).collect()
+}
+
+fn neighbor_sums(adjacency: &Adjacency, values: &[f64]) -> Vec<f64> {
+    adjacency
+        .outgoing
+        .par_iter()
+        .map(|edges| {
+            edges.iter().fold(0.0, |sum, &(neighbor, weight)| {
+                sum + values[neighbor] * weight
+            })
+        })
+        .collect()
+}
+
+fn difference(left: &[f64], right: &[f64]) -> f64 {
+    left.par_iter()
+        .zip(right.par_iter())
+        .map(|(left, right)| (left - right).abs())
+        .sum()
+}
+
+fn scale(values: &mut [f64], factor: f64) {
+    values.par_iter_mut().for_each(|value| *value *= factor);
+}
+
+fn normalize(values: &mut [f64], norm: fn(&[f64]) -> f64) {
+    let norm = norm(values);
+
+    if norm > 0.0 {
+        scale(values, 1.0 / norm);
+    }
+}
+
+fn euclidean_norm(values: &[f64]) -> f64 {
+    values
+        .par_iter()
+        .map(|value| value * value)
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn max_norm(values: &[f64]) -> f64 {
+    values.par_iter().copied().reduce(|| 0.0, f64::max)
+}
+
+fn sum_norm(values: &[f64]) -> f64 {
+    values.par_iter().sum()
+}
+
+pub(crate) fn degree_centrality(
+    view: &GraphView<'_>,
+    direction: Direction,
+    weight: Option<&AttributeKey>,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, weight)?;
+    let node_count = adjacency.node_count();
+
+    if node_count <= 1 {
+        return Ok(node_values(&adjacency, vec![1.0; node_count]));
+    }
+
+    let weighted_degrees = |adjacency: &Adjacency| -> Vec<f64> {
+        adjacency
+            .outgoing
+            .par_iter()
+            .map(|edges| {
+                edges
+                    .iter()
+                    .fold(0.0, |degree, (_, weight)| degree + weight)
+            })
+            .collect()
+    };
+
+    let mut degrees = match direction {
+        Direction::Incoming => weighted_degrees(&adjacency.reversed()),
+        _ => weighted_degrees(&adjacency),
+    };
+
+    if direction == Direction::Both && !view.is_undirected() {
+        for (degree, in_degree) in degrees
+            .iter_mut()
+            .zip(weighted_degrees(&adjacency.reversed()))
+        {
+            *degree += in_degree;
+        }
+    }
+
+    scale(&mut degrees, 1.0 / (node_count - 1) as f64);
+
+    Ok(node_values(&adjacency, degrees))
+}
+
+pub(crate) fn closeness_centrality(
+    view: &GraphView<'_>,
+    weight: Option<&AttributeKey>,
+    wf_improved: bool,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let adjacency = checked_adjacency(view, weight, "Closeness centrality")?.reversed();
+    let node_count = adjacency.node_count();
+
+    let values = (0..node_count)
+        .into_par_iter()
+        .map(|position| {
+            let (reachable, total) = dijkstra(&adjacency, position, None, None)
+                .distances
+                .into_iter()
+                .flatten()
+                .fold((0, 0.0), |(reachable, total), distance| {
+                    (reachable + 1, total + distance)
+                });
+
+            if total <= 0.0 || node_count <= 1 {
+                return 0.0;
+            }
+
+            let closeness = (reachable - 1) as f64 / total;
+
+            if wf_improved {
+                closeness * (reachable - 1) as f64 / (node_count - 1) as f64
+            } else {
+                closeness
+            }
+        })
+        .collect();
+
+    Ok(node_values(&adjacency, values))
+}
+
+#[derive(Debug)]
+struct ShortestPathCounts {
+    order: Vec<usize>,
+    predecessors: Vec<Vec<(usize, usize)>>,
+    path_counts: Vec<f64>,
+}
+
+impl ShortestPathCounts {
+    fn new(adjacency: &Adjacency, source: usize, weighted: bool) -> Self {
+        let mut counts = Self {
+            order: Vec::with_capacity(adjacency.node_count()),
+            predecessors: vec![Vec::new(); adjacency.node_count()],
+            path_counts: vec![0.0; adjacency.node_count()],
+        };
+
+        counts.path_counts[source] = 1.0;
+
+        if weighted {
+            counts.search_weighted(adjacency, source);
+        } else {
+            counts.search_unweighted(adjacency, source);
+        }
+
+        counts
+    }
+
+    fn search_unweighted(&mut self, adjacency: &Adjacency, source: usize) {
+        let mut distances = vec![None; adjacency.node_count()];
+        distances[source] = Some(0);
+
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[position].expect("Queued nodes must have a distance") + 1;
+
+            for (slot, &(neighbor, _)) in adjacency.outgoing[position].iter().enumerate() {
+                if distances[neighbor].is_none() {
+                    distances[neighbor] = Some(distance);
+                    queue.push_back(neighbor);
+                }
+
+                if distances[neighbor] == Some(distance) {
+                    self.path_counts[neighbor] += self.path_counts[position];
+                    self.predecessors[neighbor].push((position, slot));
+                }
+            }
+
+            self.order.push(position);
+        }
+    }
+
+    fn search_weighted(&mut self, adjacency: &Adjacency, source: usize) {
+        let mut distances = vec![None; adjacency.node_count()];
+        distances[source] = Some(0.0);
+
+        let mut settled = vec![false; adjacency.node_count()];
+        let mut heap = BinaryHeap::from([HeapEntry {
+            distance: 0.0,
+            position: source,
+        }]);
+
+        while let Some(HeapEntry { distance, position }) = heap.pop() {
+            if settled[position] {
+                continue;
+            }
+
+            settled[position] = true;
+
+            for (slot, &(neighbor, weight)) in adjacency.outgoing[position].iter().enumerate() {
+                if settled[neighbor] {
+                    continue;
+                }
+
+                let candidate = distance + weight;
+
+                match distances[neighbor] {
+                    Some(current) if candidate > current => {}
+                    Some(current) if candidate == current => {
+                        self.path_counts[neighbor] += self.path_counts[position];
+                        self.predecessors[neighbor].push((position, slot));
+                    }
+                    _ => {
+                        distances[neighbor] = Some(candidate);
+                        self.path_counts[neighbor] = self.path_counts[position];
+                        self.predecessors[neighbor] = vec![(position, slot)];
+                        heap.push(HeapEntry {
+                            distance: candidate,
+                            position: neighbor,
+                        });
+                    }
+                }
+            }
+
+            self.order.push(position);
+        }
+    }
+
+    fn dependencies(&self, mut visit_edge: impl FnMut(usize, usize, f64)) -> Vec<f64> {
+        let mut dependencies = vec![0.0; self.path_counts.len()];
+
+        for &position in self.order.iter().rev() {
+            let coefficient = (1.0 + dependencies[position]) / self.path_counts[position];
+
+            for &(predecessor, slot) in &self.predecessors[position] {
+                let dependency = self.path_counts[predecessor] * coefficient;
+
+                dependencies[predecessor] += dependency;
+                visit_edge(predecessor, slot, dependency);
+            }
+        }
+
+        dependencies
+    }
+}
+
+fn sample_sources(
+    node_count: usize,
+    k: Option<usize>,
+    seed: Option<u64>,
+) -> Result<Vec<usize>, PyGraphsterError> {
+    let Some(k) = k else {
+        return Ok((0..node_count).collect());
+    };
+
+    if k > node_count {
+        return Err(GraphsterError::ConversionError(format!(
+            "Sample size {} is larger than the number of nodes {}",
+            k, node_count
+        ))
+        .into());
+    }
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    Ok(sample(&mut rng, node_count, k).into_vec())
+}
+
+fn brandes(
+    adjacency: &Adjacency,
+    sources: &[usize],
+    weighted: bool,
+    size: usize,
+    accumulate: impl Fn(&ShortestPathCounts, usize, &mut [f64]) + Sync,
+) -> Vec<f64> {
+    sources
+        .par_iter()
+        .fold(
+            || vec![0.0; size],
+            |mut values, &source| {
+                let counts = ShortestPathCounts::new(adjacency, source, weighted);
+                accumulate(&counts, source, &mut values);
+                values
+            },
+        )
+        .reduce(
+            || vec![0.0; size],
+            |mut left, right| {
+                for (left, right) in left.iter_mut().zip(right) {
+                    *left += right;
+                }
+
+                left
+            },
+        )
+}
+
+pub(crate) fn betweenness_centrality(
+    view: &GraphView<'_>,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&AttributeKey>,
+    endpoints: bool,
+    seed: Option<u64>,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let adjacency = checked_adjacency(view, weight, "Betweenness centrality")?;
+    let node_count = adjacency.node_count();
+    let sources = sample_sources(node_count, k, seed)?;
+
+    let mut values = brandes(
+        &adjacency,
+        &sources,
+        weight.is_some(),
+        node_count,
+        |counts, source, values| {
+            if endpoints {
+                values[source] += (counts.order.len() - 1) as f64;
+            }
+
+            let dependencies = counts.dependencies(|_, _, _| {});
+
+            for &position in &counts.order {
+                if position != source {
+                    values[position] += dependencies[position] + if endpoints { 1.0 } else { 0.0 };
+                }
+            }
+        },
+    );
+
+    let total = if endpoints {
+        node_count
+    } else {
+        node_count.saturating_sub(1)
+    };
+
+    if total < 2 {
+        return Ok(node_values(&adjacency, values));
+    }
+
+    let unit = if normalized {
+        (total - 1) as f64
+    } else if view.is_undirected() {
+        2.0 / total as f64
+    } else {
+        1.0 / total as f64
+    };
+
+    match k.filter(|&k| k > 0 && k < node_count) {
+        Some(k) if !endpoints => {
+            // Without endpoints a sampled source cannot lie on its own paths, so it only
+            // collects from the other k - 1 sources.
+            let mut factors = vec![1.0 / (k as f64 * unit); node_count];
+
+            for &source in &sources {
+                factors[source] = 1.0 / ((k - 1) as f64 * unit);
+            }
+
+            for (value, factor) in values.iter_mut().zip(factors) {
+                *value *= factor;
+            }
+        }
+        Some(k) => scale(&mut values, 1.0 / (k as f64 * unit)),
+        None => scale(&mut values, 1.0 / (total as f64 * unit)),
+    }
+
+    Ok(node_values(&adjacency, values))
+}
+
+pub(crate) fn edge_betweenness_centrality(
+    view: &GraphView<'_>,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&AttributeKey>,
+    seed: Option<u64>,
+) -> Result<Vec<(EdgeIndex, f64)>, PyGraphsterError> {
+    let adjacency = checked_adjacency(view, weight, "Edge betweenness centrality")?;
+    let node_count = adjacency.node_count();
+    let sources = sample_sources(node_count, k, seed)?;
+
+    let mut edge_indices = Vec::new();
+    let mut edge_positions = HashMap::new();
+    let mut edge_slots = Vec::with_capacity(node_count);
+
+    for edges in &adjacency.edge_indices {
+        let mut slots = Vec::with_capacity(edges.len());
+
+        for edge_index in edges {
+            slots.push(*edge_positions.entry(*edge_index).or_insert_with(|| {
+                edge_indices.push(*edge_index);
+                edge_indices.len() - 1
+            }));
+        }
+
+        edge_slots.push(slots);
+    }
+
+    let mut values = brandes(
+        &adjacency,
+        &sources,
+        weight.is_some(),
+        edge_indices.len(),
+        |counts, _, values| {
+            counts.dependencies(|predecessor, slot, dependency| {
+                values[edge_slots[predecessor][slot]] += dependency;
+            });
+        },
+    );
+
+    if node_count >= 2 {
+        let sources = k.filter(|&k| k > 0).unwrap_or(node_count) as f64;
+
+        scale(
+            &mut values,
+            if normalized {
+                1.0 / (sources * (node_count - 1) as f64)
+            } else if view.is_undirected() {
+                node_count as f64 / (2.0 * sources)
+            } else {
+                node_count as f64 / sources
+            },
+        );
+    }
+
+    Ok(edge_indices.into_iter().zip(values).collect())
+}
+
+pub(crate) fn eigenvector_centrality(
+    view: &GraphView<'_>,
+    max_iter: usize,
+    tol: f64,
+    weight: Option<&AttributeKey>,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let incoming = Adjacency::new(view, weight)?.reversed();
+    let node_count = incoming.node_count();
+
+    if node_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut values = vec![1.0 / node_count as f64; node_count];
+
+    for _ in 0..max_iter {
+        let mut next = neighbor_sums(&incoming, &values)
+            .into_par_iter()
+            .zip(values.par_iter())
+            .map(|(sum, value)| sum + value)
+            .collect::<Vec<_>>();
+
+        normalize(&mut next, euclidean_norm);
+
+        let error = difference(&next, &values);
+        values = next;
+
+        if error < node_count as f64 * tol {
+            return Ok(node_values(&incoming, values));
+        }
+    }
+
+    Err(convergence_error("Eigenvector centrality", max_iter))
+}
+
+pub(crate) fn katz_centrality(
+    view: &GraphView<'_>,
+    alpha: f64,
+    beta: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    weight: Option<&AttributeKey>,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let incoming = Adjacency::new(view, weight)?.reversed();
+    let node_count = incoming.node_count();
+
+    if node_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut values = vec![0.0; node_count];
+
+    for _ in 0..max_iter {
+        let next = neighbor_sums(&incoming, &values)
+            .into_par_iter()
+            .map(|sum| alpha * sum + beta)
+            .collect::<Vec<_>>();
+
+        let error = difference(&next, &values);
+        values = next;
+
+        if error < node_count as f64 * tol {
+            if normalized {
+                normalize(&mut values, euclidean_norm);
+            }
+
+            return Ok(node_values(&incoming, values));
+        }
+    }
+
+    Err(convergence_error("Katz centrality", max_iter))
+}
+
+pub(crate) type HitsScores = (Vec<(AttributeKey, f64)>, Vec<(AttributeKey, f64)>);
+
+pub(crate) fn hits(
+    view: &GraphView<'_>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    weight: Option<&AttributeKey>,
+) -> Result<HitsScores, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, weight)?;
+    let incoming = adjacency.reversed();
+    let node_count = adjacency.node_count();
+
+    if node_count == 0 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let mut hubs = vec![1.0 / node_count as f64; node_count];
+
+    for _ in 0..max_iter {
+        let mut authorities = neighbor_sums(&incoming, &hubs);
+        let mut next = neighbor_sums(&adjacency, &authorities);
+
+        normalize(&mut next, max_norm);
+        normalize(&mut authorities, max_norm);
+
+        let error = difference(&next, &hubs);
+        hubs = next;
+
+        if error < tol {
+            if normalized {
+                normalize(&mut hubs, sum_norm);
+                normalize(&mut authorities, sum_norm);
+            }
+
+            return Ok((
+                node_values(&adjacency, hubs),
+                node_values(&adjacency, authorities),
+            ));
+        }
+    }
+
+    Err(convergence_error("HITS", max_iter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::{
+        tests::{assert_values, graph, index},
+        ViewDefinition,
+    };
+    use graphster::graph::DataGraph;
+
+    fn undirected_graph() -> DataGraph {
+        graph(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (1, 3, 1.0),
+            (3, 4, 1.0),
+            (4, 5, 1.0),
+        ])
+    }
+
+    fn cyclic_graph() -> DataGraph {
+        graph(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 0, 1.0),
+            (2, 3, 1.0),
+            (3, 1, 1.0),
+        ])
+    }
+
+    #[test]
+    fn betweenness_matches_networkx() {
+        let graph = undirected_graph();
+        let definition = ViewDefinition::default().undirected();
+        let view = GraphView::new(&graph, &definition);
+
+        assert_values(
+            betweenness_centrality(&view, None, true, None, false, None).unwrap(),
+            &[(0, 0.0), (1, 0.4), (2, 0.0), (3, 0.6), (4, 0.4), (5, 0.0)],
+        );
+        assert_values(
+            betweenness_centrality(&view, None, false, None, false, None).unwrap(),
+            &[(0, 0.0), (1, 4.0), (2, 0.0), (3, 6.0), (4, 4.0), (5, 0.0)],
+        );
+        assert_values(
+            betweenness_centrality(&view, None, true, None, true, None).unwrap(),
+            &[
+                (0, 1.0 / 3.0),
+                (1, 0.6),
+                (2, 1.0 / 3.0),
+                (3, 11.0 / 15.0),
+                (4, 0.6),
+                (5, 1.0 / 3.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn directed_betweenness_matches_networkx() {
+        let graph = graph(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 0, 1.0),
+            (2, 3, 1.0),
+            (3, 1, 1.0),
+            (3, 4, 1.0),
+        ]);
+        let view = GraphView::from(&graph);
+
+        assert_values(
+            betweenness_centrality(&view, None, true, None, false, None).unwrap(),
+            &[
+                (0, 1.0 / 24.0),
+                (1, 10.0 / 24.0),
+                (2, 0.5),
+                (3, 7.0 / 24.0),
+                (4, 0.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn sampled_betweenness_is_rescaled_like_networkx() {
+        // Every sample of a star recovers the exact centre value once sampled sources
+        // are scaled by k - 1, so the result does not depend on the drawn nodes.
+        let graph = graph(&[(0, 1, 1.0), (0, 2, 1.0), (0, 3, 1.0), (0, 4, 1.0)]);
+        let definition = ViewDefinition::default().undirected();
+        let view = GraphView::new(&graph, &definition);
+
+        for k in [2, 3] {
+            for seed in 0..4 {
+                assert_values(
+                    betweenness_centrality(&view, Some(k), true, None, false, Some(seed)).unwrap(),
+                    &[(0, 1.0), (1, 0.0), (2, 0.0), (3, 0.0), (4, 0.0)],
+                );
+                assert_values(
+                    betweenness_centrality(&view, Some(k), false, None, false, Some(seed)).unwrap(),
+                    &[(0, 6.0), (1, 0.0), (2, 0.0), (3, 0.0), (4, 0.0)],
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edge_betweenness_matches_networkx() {
+        let graph = undirected_graph();
+        let definition = ViewDefinition::default().undirected();
+        let view = GraphView::new(&graph, &definition);
+
+        let values = edge_betweenness_centrality(&view, None, true, None, None)
+            .unwrap()
+            .into_iter()
+            .map(|(edge_index, value)| {
+                let (source, target) = graph.edge_endpoints(&edge_index).unwrap();
+
+                ((index(&source.0), index(&target.0)), value)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let expected = [
+            ((0, 1), 1.0 / 3.0),
+            ((1, 2), 2.0 / 15.0),
+            ((1, 3), 0.4),
+            ((2, 3), 0.2),
+            ((3, 4), 8.0 / 15.0),
+            ((4, 5), 1.0 / 3.0),
+        ];
+
+        assert_eq!(values.len(), expected.len());
+        for (edge, value) in expected {
+            assert!((values[&edge] - value).abs() < 1e-6, "{:?}", edge);
+        }
+    }
+
+    #[test]
+    fn sampled_directed_edge_betweenness_is_rescaled_like_networkx() {
+        // Every sample of a directed cycle is a rotation of the others, so the sorted
+        // values do not depend on the drawn nodes.
+        let graph = graph(&[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0)]);
+        let view = GraphView::from(&graph);
+
+        for (k, normalized, expected) in [
+            (1, false, [0.0, 3.0, 6.0]),
+            (2, false, [1.5, 3.0, 4.5]),
+            (3, false, [3.0, 3.0, 3.0]),
+            (2, true, [0.25, 0.5, 0.75]),
+        ] {
+            for seed in 0..4 {
+                let mut values =
+                    edge_betweenness_centrality(&view, Some(k), normalized, None, Some(seed))
+                        .unwrap()
+                        .into_iter()
+                        .map(|(_, value)| value)
+                        .collect::<Vec<_>>();
+                values.sort_by(f64::total_cmp);
+
+                assert_eq!(values.len(), expected.len());
+                for (value, expected) in values.iter().zip(expected) {
+                    assert!((value - expected).abs() < 1e-6, "k {}: {:?}", k, values);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn katz_matches_networkx() {
+        let graph = cyclic_graph();
+        let view = GraphView::from(&graph);
+
+        assert_values(
+            katz_centrality(&view, 0.1, 1.0, 1000, 1e-6, true, None).unwrap(),
+            &[
+                (0, 0.48643990759528033),
+                (1, 0.5346456484431836),
+                (2, 0.49082223176844597),
+                (3, 0.48643990759528033),
+            ],
+        );
+        assert!(matches!(
+            katz_centrality(&view, 0.9, 1.0, 1000, 1e-6, true, None),
+            Err(PyGraphsterError::Convergence(_))
+        ));
+    }
+
+    #[test]
+    fn eigenvector_matches_networkx() {
+        let graph = cyclic_graph();
+        let view = GraphView::from(&graph);
+
+        let values = eigenvector_centrality(&view, 100, 1e-6, None).unwrap();
+        let expected = [
+            (0, 0.40464740714322783),
+            (1, 0.6423391474460629),
+            (2, 0.509824843885418),
+            (3, 0.40464740714322783),
+        ];
+
+        for (node_index, value) in values {
+            let (_, expected) = expected[index(&node_index)];
+            assert!((value - expected).abs() < 1e-4, "{}", node_index);
+        }
+        assert!(matches!(
+            eigenvector_centrality(&view, 2, 1e-6, None),
+            Err(PyGraphsterError::Convergence(_))
+        ));
+    }
+
+    #[test]
+    fn hits_matches_networkx() {
+        let graph = cyclic_graph();
+        let view = GraphView::from(&graph);
+
+        let (hubs, authorities) = hits(&view, 100, 1e-8, true, None).unwrap();
+
+        assert_values(
+            hubs,
+            &[(0, 1.0 / 3.0), (1, 0.0), (2, 1.0 / 3.0), (3, 1.0 / 3.0)],
+        );
+        assert_values(authorities, &[(0, 0.25), (1, 0.5), (2, 0.0), (3, 0.25)]);
+        assert!(matches!(
+            hits(&view, 2, 1e-8, true, None),
+            Err(PyGraphsterError::Convergence(_))
+        ));
+    }
+}
@@ -0,0 +1,277 @@
+use super::{adjacency::Adjacency, topology::GraphView};
+use crate::errors::PyGraphsterError;
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    graph::DataGraph,
+};
+use std::collections::{BTreeMap, HashMap};
+
+fn find_root(parents: &mut [usize], mut position: usize) -> usize {
+    while parents[position] != position {
+        parents[position] = parents[parents[position]];
+        position = parents[position];
+    }
+
+    position
+}
+
+fn weakly_connected_positions(adjacency: &Adjacency) -> Vec<Vec<usize>> {
+    let mut parents = (0..adjacency.node_count()).collect::<Vec<_>>();
+
+    for (position, edges) in adjacency.outgoing.iter().enumerate() {
+        for &(neighbor, _) in edges {
+            let (root, neighbor_root) = (
+                find_root(&mut parents, position),
+                find_root(&mut parents, neighbor),
+            );
+
+            parents[root.max(neighbor_root)] = root.min(neighbor_root);
+        }
+    }
+
+    let mut components = Vec::<Vec<usize>>::new();
+    let mut component_ids = HashMap::new();
+
+    for position in 0..adjacency.node_count() {
+        let root = find_root(&mut parents, position);
+        let component_id = *component_ids.entry(root).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+
+        components[component_id].push(position);
+    }
+
+    components
+}
+
+fn strongly_connected_positions(adjacency: &Adjacency) -> Vec<Vec<usize>> {
+    let node_count = adjacency.node_count();
+    let mut indices = vec![None; node_count];
+    let mut lowlinks = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for start in 0..node_count {
+        if indices[start].is_some() {
+            continue;
+        }
+
+        indices[start] = Some(next_index);
+        lowlinks[start] = next_index;
+        next_index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        let mut call_stack = vec![(start, 0)];
+
+        while let Some((position, next_edge)) = call_stack.last_mut() {
+            let position = *position;
+
+            if let Some(&(neighbor, _)) = adjacency.outgoing[position].get(*next_edge) {
+                *next_edge += 1;
+
+                match indices[neighbor] {
+                    None => {
+                        indices[neighbor] = Some(next_index);
+                        lowlinks[neighbor] = next_index;
+                        next_index += 1;
+                        stack.push(neighbor);
+                        on_stack[neighbor] = true;
+                        call_stack.push((neighbor, 0));
+                    }
+                    Some(neighbor_index) if on_stack[neighbor] => {
+                        lowlinks[position] = lowlinks[position].min(neighbor_index);
+                    }
+                    Some(_) => {}
+                }
+
+                continue;
+            }
+
+            call_stack.pop();
+
+            if let Some((parent, _)) = call_stack.last() {
+                lowlinks[*parent] = lowlinks[*parent].min(lowlinks[position]);
+            }
+
+            if Some(lowlinks[position]) == indices[position] {
+                let mut component = Vec::new();
+
+                loop {
+                    let member = stack.pop().expect("Stack must contain the component root");
+                    on_stack[member] = false;
+                    component.push(member);
+
+                    if member == position {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+    }
+
+    components.reverse();
+
+    components
+}
+
+fn resolve(adjacency: &Adjacency, components: Vec<Vec<usize>>) -> Vec<Vec<AttributeKey>> {
+    components
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(|position| adjacency.node_indices[position].clone())
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn weakly_connected_components(
+    view: &GraphView<'_>,
+) -> Result<Vec<Vec<AttributeKey>>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+    let components = weakly_connected_positions(&adjacency);
+
+    Ok(resolve(&adjacency, components))
+}
+
+pub(crate) fn strongly_connected_components(
+    view: &GraphView<'_>,
+) -> Result<Vec<Vec<AttributeKey>>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+    let components = strongly_connected_positions(&adjacency);
+
+    Ok(resolve(&adjacency, components))
+}
+
+pub(crate) fn is_weakly_connected(view: &GraphView<'_>) -> Result<bool, PyGraphsterError> {
+    Ok(weakly_connected_components(view)?.len() == 1)
+}
+
+pub(crate) fn is_strongly_connected(view: &GraphView<'_>) -> Result<bool, PyGraphsterError> {
+    Ok(strongly_connected_components(view)?.len() == 1)
+}
+
+pub(crate) fn component_values(
+    components: &[Vec<AttributeKey>],
+) -> Vec<(AttributeKey, AttributeValue)> {
+    components
+        .iter()
+        .enumerate()
+        .flat_map(|(component_id, component)| {
+            component
+                .iter()
+                .map(move |node_index| (node_index.clone(), AttributeValue::Usize(component_id)))
+        })
+        .collect()
+}
+
+// AttributeValue has no list variant, so the component members cannot be stored on the
+// condensed nodes and are returned next to the graph instead.
+pub(crate) fn condensation(
+    view: &GraphView<'_>,
+) -> Result<(DataGraph, Vec<Vec<AttributeKey>>), PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+    let components = strongly_connected_positions(&adjacency);
+
+    let mut component_ids = vec![0; adjacency.node_count()];
+
+    for (component_id, component) in components.iter().enumerate() {
+        for &position in component {
+            component_ids[position] = component_id;
+        }
+    }
+
+    let mut edge_counts = BTreeMap::new();
+
+    for (position, edges) in adjacency.outgoing.iter().enumerate() {
+        for &(neighbor, _) in edges {
+            let (source, target) = (component_ids[position], component_ids[neighbor]);
+
+            if source != target {
+                *edge_counts.entry((source, target)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut graph = DataGraph::from_nodes(
+        components
+            .iter()
+            .enumerate()
+            .map(|(component_id, component)| {
+                (
+                    AttributeKey::Usize(component_id),
+                    HashMap::from([(
+                        AttributeKey::String("size".to_string()),
+                        AttributeValue::Usize(component.len()),
+                    )]),
+                )
+            })
+            .collect(),
+    );
+
+    for ((source, target), edge_count) in edge_counts {
+        graph.add_edge(
+            AttributeKey::Usize(source),
+            AttributeKey::Usize(target),
+            HashMap::from([(
+                AttributeKey::String("edge_count".to_string()),
+                AttributeValue::Usize(edge_count),
+            )]),
+        )?;
+    }
+
+    Ok((graph, resolve(&adjacency, components)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::tests::{graph, index};
+
+    fn sorted(components: Vec<Vec<AttributeKey>>) -> Vec<Vec<usize>> {
+        let mut components = components
+            .iter()
+            .map(|component| {
+                let mut component = component.iter().map(index).collect::<Vec<_>>();
+                component.sort_unstable();
+                component
+            })
+            .collect::<Vec<_>>();
+        components.sort_unstable();
+
+        components
+    }
+
+    #[test]
+    fn strongly_connected_components_match_networkx() {
+        let graph = graph(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 0, 1.0),
+            (2, 3, 1.0),
+            (3, 4, 1.0),
+            (4, 3, 1.0),
+            (4, 5, 1.0),
+            (6, 6, 1.0),
+            (6, 5, 1.0),
+        ]);
+        let view = GraphView::from(&graph);
+
+        assert_eq!(
+            sorted(strongly_connected_components(&view).unwrap()),
+            vec![vec![0, 1, 2], vec![3, 4], vec![5], vec![6]]
+        );
+        assert_eq!(
+            sorted(weakly_connected_components(&view).unwrap()),
+            vec![vec![0, 1, 2, 3, 4, 5, 6]]
+        );
+        assert!(!is_strongly_connected(&view).unwrap());
+    }
+}
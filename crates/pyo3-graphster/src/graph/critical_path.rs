@@ -0,0 +1,401 @@
+use super::{
+    adjacency::{node_weight, Adjacency, WeightSource},
+    dag::{resolve, topological_positions},
+    topology::GraphView,
+    PyNodeIndex,
+};
+use crate::{datatypes::IntoPyNative, errors::PyGraphsterError};
+use graphster::datatypes::{AttributeKey, AttributeValue};
+use pyo3::{pyclass, pymethods, PyObject, Python};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Schedule {
+    adjacency: Adjacency,
+    durations: Vec<f64>,
+    earliest_starts: Vec<f64>,
+    predecessors: Vec<Option<usize>>,
+    order: Vec<usize>,
+}
+
+impl Schedule {
+    fn new(
+        view: &GraphView<'_>,
+        weight: Option<&AttributeKey>,
+        weight_source: WeightSource,
+    ) -> Result<Self, PyGraphsterError> {
+        let (adjacency, durations) = match (weight, weight_source) {
+            (Some(weight), WeightSource::Node) => {
+                let mut adjacency = Adjacency::new(view, None)?;
+
+                for edges in &mut adjacency.outgoing {
+                    for (_, edge_weight) in edges {
+                        *edge_weight = 0.0;
+                    }
+                }
+
+                let durations = adjacency
+                    .node_indices
+                    .par_iter()
+                    .map(|node_index| node_weight(view, node_index, weight))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                (adjacency, durations)
+            }
+            _ => {
+                let adjacency = Adjacency::new(view, weight)?;
+                let durations = vec![0.0; adjacency.node_count()];
+
+                (adjacency, durations)
+            }
+        };
+
+        let order = topological_positions(&adjacency)?;
+        let mut earliest_starts = vec![0.0; adjacency.node_count()];
+        let mut predecessors = vec![None; adjacency.node_count()];
+
+        for &position in &order {
+            let finish = earliest_starts[position] + durations[position];
+
+            for &(neighbor, edge_weight) in &adjacency.outgoing[position] {
+                let candidate = finish + edge_weight;
+
+                if candidate > earliest_starts[neighbor]
+                    || (predecessors[neighbor].is_none() && candidate == earliest_starts[neighbor])
+                {
+                    earliest_starts[neighbor] = candidate;
+                    predecessors[neighbor] = Some(position);
+                }
+            }
+        }
+
+        Ok(Self {
+            adjacency,
+            durations,
+            earliest_starts,
+            predecessors,
+            order,
+        })
+    }
+
+    fn earliest_finish(&self, position: usize) -> f64 {
+        self.earliest_starts[position] + self.durations[position]
+    }
+
+    fn duration(&self) -> f64 {
+        (0..self.adjacency.node_count())
+            .map(|position| self.earliest_finish(position))
+            .fold(0.0, f64::max)
+    }
+
+    fn longest_path(&self) -> Vec<usize> {
+        let Some(mut position) = (0..self.adjacency.node_count()).max_by(|left, right| {
+            self.earliest_finish(*left)
+                .total_cmp(&self.earliest_finish(*right))
+        }) else {
+            return Vec::new();
+        };
+
+        let mut path = vec![position];
+
+        while let Some(predecessor) = self.predecessors[position] {
+            path.push(predecessor);
+            position = predecessor;
+        }
+
+        path.reverse();
+
+        path
+    }
+
+    fn latest_finishes(&self) -> Vec<f64> {
+        let mut latest_finishes = vec![self.duration(); self.adjacency.node_count()];
+
+        for &position in self.order.iter().rev() {
+            for &(neighbor, edge_weight) in &self.adjacency.outgoing[position] {
+                latest_finishes[position] = latest_finishes[position]
+                    .min(latest_finishes[neighbor] - self.durations[neighbor] - edge_weight);
+            }
+        }
+
+        latest_finishes
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NodeSchedule {
+    pub(crate) earliest_start: f64,
+    pub(crate) earliest_finish: f64,
+    pub(crate) latest_start: f64,
+    pub(crate) latest_finish: f64,
+    pub(crate) slack: f64,
+}
+
+type ScheduleField = fn(&NodeSchedule) -> f64;
+
+const SCHEDULE_FIELDS: [(&str, ScheduleField); 5] = [
+    ("earliest_start", |schedule| schedule.earliest_start),
+    ("earliest_finish", |schedule| schedule.earliest_finish),
+    ("latest_start", |schedule| schedule.latest_start),
+    ("latest_finish", |schedule| schedule.latest_finish),
+    ("slack", |schedule| schedule.slack),
+];
+
+#[derive(Debug)]
+pub(crate) struct CriticalPathAnalysis {
+    pub(crate) duration: f64,
+    pub(crate) critical_path: Vec<AttributeKey>,
+    pub(crate) schedule: Vec<(AttributeKey, NodeSchedule)>,
+}
+
+impl CriticalPathAnalysis {
+    pub(crate) fn attribute_values(
+        &self,
+        attribute_prefix: &str,
+    ) -> Vec<(AttributeKey, Vec<(AttributeKey, AttributeValue)>)> {
+        SCHEDULE_FIELDS
+            .into_iter()
+            .map(|(name, field)| {
+                (
+                    AttributeKey::String(format!("{}{}", attribute_prefix, name)),
+                    self.schedule
+                        .iter()
+                        .map(|(node_index, schedule)| {
+                            (node_index.clone(), AttributeValue::Float64(field(schedule)))
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn dag_longest_path(
+    view: &GraphView<'_>,
+    weight: Option<&AttributeKey>,
+    weight_source: WeightSource,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    let schedule = Schedule::new(view, weight, weight_source)?;
+    let path = schedule.longest_path();
+
+    Ok(resolve(&schedule.adjacency, path))
+}
+
+pub(crate) fn critical_path_analysis(
+    view: &GraphView<'_>,
+    weight: Option<&AttributeKey>,
+    weight_source: WeightSource,
+) -> Result<CriticalPathAnalysis, PyGraphsterError> {
+    let schedule = Schedule::new(view, weight, weight_source)?;
+    let latest_finishes = schedule.latest_finishes();
+
+    let node_schedules = (0..schedule.adjacency.node_count())
+        .map(|position| {
+            let latest_start = latest_finishes[position] - schedule.durations[position];
+
+            (
+                schedule.adjacency.node_indices[position].clone(),
+                NodeSchedule {
+                    earliest_start: schedule.earliest_starts[position],
+                    earliest_finish: schedule.earliest_finish(position),
+                    latest_start,
+                    latest_finish: latest_finishes[position],
+                    slack: latest_start - schedule.earliest_starts[position],
+                },
+            )
+        })
+        .collect();
+
+    Ok(CriticalPathAnalysis {
+        duration: schedule.duration(),
+        critical_path: resolve(&schedule.adjacency, schedule.longest_path()),
+        schedule: node_schedules,
+    })
+}
+
+#[pyclass]
+#[derive(Debug)]
+pub struct PyCriticalPathAnalysis {
+    duration: f64,
+    critical_path: PyObject,
+    earliest_start: PyObject,
+    earliest_finish: PyObject,
+    latest_start: PyObject,
+    latest_finish: PyObject,
+    slack: PyObject,
+}
+
+impl PyCriticalPathAnalysis {
+    pub(crate) fn new(analysis: CriticalPathAnalysis, native_types: bool, py: Python<'_>) -> Self {
+        let field = |field: ScheduleField| {
+            analysis
+                .schedule
+                .iter()
+                .map(|(node_index, schedule)| {
+                    (PyNodeIndex::from(node_index.clone()), field(schedule))
+                })
+                .collect::<HashMap<_, _>>()
+                .into_py_with_mode(py, native_types)
+        };
+
+        Self {
+            duration: analysis.duration,
+            critical_path: analysis
+                .critical_path
+                .iter()
+                .cloned()
+                .map(PyNodeIndex::from)
+                .collect::<Vec<_>>()
+                .into_py_with_mode(py, native_types),
+            earliest_start: field(|schedule| schedule.earliest_start),
+            earliest_finish: field(|schedule| schedule.earliest_finish),
+            latest_start: field(|schedule| schedule.latest_start),
+            latest_finish: field(|schedule| schedule.latest_finish),
+            slack: field(|schedule| schedule.slack),
+        }
+    }
+}
+
+#[pymethods]
+impl PyCriticalPathAnalysis {
+    #[getter]
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    #[getter]
+    fn critical_path(&self, py: Python<'_>) -> PyObject {
+        self.critical_path.clone_ref(py)
+    }
+
+    #[getter]
+    fn earliest_start(&self, py: Python<'_>) -> PyObject {
+        self.earliest_start.clone_ref(py)
+    }
+
+    #[getter]
+    fn earliest_finish(&self, py: Python<'_>) -> PyObject {
+        self.earliest_finish.clone_ref(py)
+    }
+
+    #[getter]
+    fn latest_start(&self, py: Python<'_>) -> PyObject {
+        self.latest_start.clone_ref(py)
+    }
+
+    #[getter]
+    fn latest_finish(&self, py: Python<'_>) -> PyObject {
+        self.latest_finish.clone_ref(py)
+    }
+
+    #[getter]
+    fn slack(&self, py: Python<'_>) -> PyObject {
+        self.slack.clone_ref(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::tests::{graph, index, node, weight};
+
+    const EDGES: [(usize, usize, f64); 7] = [
+        (0, 1, 3.0),
+        (0, 2, 2.0),
+        (1, 3, 4.0),
+        (2, 3, 1.0),
+        (2, 4, 6.0),
+        (3, 5, 2.0),
+        (4, 5, 0.5),
+    ];
+
+    fn indices(node_indices: &[AttributeKey]) -> Vec<usize> {
+        node_indices.iter().map(index).collect()
+    }
+
+    #[test]
+    fn dag_longest_path_matches_networkx() {
+        let mut graph = graph(&EDGES);
+        let duration = AttributeKey::String("duration".to_string());
+
+        for (node_index, value) in [(0, 2.0), (1, 1.0), (2, 3.0), (3, 2.0), (4, 1.0), (5, 4.0)] {
+            graph
+                .node_attributes_mut(node(node_index))
+                .unwrap()
+                .insert(duration.clone(), AttributeValue::Float64(value));
+        }
+
+        let view = GraphView::from(&graph);
+
+        assert_eq!(
+            indices(&dag_longest_path(&view, Some(&weight()), WeightSource::Edge).unwrap()),
+            vec![0, 1, 3, 5]
+        );
+        assert_eq!(
+            indices(&dag_longest_path(&view, Some(&duration), WeightSource::Node).unwrap()),
+            vec![0, 2, 3, 5]
+        );
+        assert_eq!(
+            dag_longest_path(&view, None, WeightSource::Edge)
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn critical_path_analysis_matches_networkx() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        let analysis = critical_path_analysis(&view, Some(&weight()), WeightSource::Edge).unwrap();
+        let schedule = analysis
+            .schedule
+            .iter()
+            .map(|(node_index, schedule)| (index(node_index), *schedule))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(analysis.duration, 9.0);
+        assert_eq!(indices(&analysis.critical_path), vec![0, 1, 3, 5]);
+        assert_eq!(schedule.len(), 6);
+
+        for (node_index, earliest_start, latest_finish, slack) in [
+            (0, 0.0, 0.0, 0.0),
+            (1, 3.0, 3.0, 0.0),
+            (2, 2.0, 2.5, 0.5),
+            (3, 7.0, 7.0, 0.0),
+            (4, 8.0, 8.5, 0.5),
+            (5, 9.0, 9.0, 0.0),
+        ] {
+            let schedule = schedule[&node_index];
+
+            assert_eq!(
+                schedule.earliest_start, earliest_start,
+                "node {}",
+                node_index
+            );
+            assert_eq!(
+                schedule.earliest_finish, earliest_start,
+                "node {}",
+                node_index
+            );
+            assert_eq!(schedule.latest_start, latest_finish, "node {}", node_index);
+            assert_eq!(schedule.latest_finish, latest_finish, "node {}", node_index);
+            assert_eq!(schedule.slack, slack, "node {}", node_index);
+        }
+    }
+
+    #[test]
+    fn cyclic_graphs_have_no_critical_path() {
+        let mut edges = EDGES.to_vec();
+        edges.push((5, 2, 1.0));
+        let graph = graph(&edges);
+        let view = GraphView::from(&graph);
+
+        assert!(matches!(
+            critical_path_analysis(&view, Some(&weight()), WeightSource::Edge),
+            Err(PyGraphsterError::Cycle(_))
+        ));
+    }
+}
@@ -0,0 +1,354 @@
+use super::{
+    adjacency::Adjacency, neighbors::Direction, topology::GraphView, traversal::Traversal,
+};
+use crate::{
+    datatypes::key::convert_attributekey_to_attributevalue, errors::PyGraphsterError,
+    query::predicate::compare_attributevalues,
+};
+use graphster::datatypes::{AttributeKey, AttributeValue};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, VecDeque},
+};
+
+fn cycle_error(cycle: &[AttributeKey]) -> PyGraphsterError {
+    PyGraphsterError::Cycle(format!(
+        "Graph contains a cycle through nodes {}",
+        cycle
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+fn in_degrees(adjacency: &Adjacency) -> Vec<usize> {
+    let mut in_degrees = vec![0; adjacency.node_count()];
+
+    for edges in &adjacency.outgoing {
+        for &(neighbor, _) in edges {
+            in_degrees[neighbor] += 1;
+        }
+    }
+
+    in_degrees
+}
+
+fn cycle_positions(adjacency: &Adjacency) -> Option<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Unvisited,
+        Active,
+        Finished,
+    }
+
+    let mut states = vec![State::Unvisited; adjacency.node_count()];
+
+    for start in 0..adjacency.node_count() {
+        if states[start] != State::Unvisited {
+            continue;
+        }
+
+        states[start] = State::Active;
+
+        let mut call_stack = vec![(start, 0)];
+
+        while let Some((position, next_edge)) = call_stack.last_mut() {
+            let position = *position;
+
+            let Some(&(neighbor, _)) = adjacency.outgoing[position].get(*next_edge) else {
+                states[position] = State::Finished;
+                call_stack.pop();
+                continue;
+            };
+
+            *next_edge += 1;
+
+            match states[neighbor] {
+                State::Unvisited => {
+                    states[neighbor] = State::Active;
+                    call_stack.push((neighbor, 0));
+                }
+                State::Active => {
+                    let cycle_start = call_stack
+                        .iter()
+                        .position(|(member, _)| *member == neighbor)
+                        .expect("Active nodes must be on the call stack");
+
+                    return Some(
+                        call_stack[cycle_start..]
+                            .iter()
+                            .map(|(member, _)| *member)
+                            .collect(),
+                    );
+                }
+                State::Finished => {}
+            }
+        }
+    }
+
+    None
+}
+
+pub(crate) fn resolve(adjacency: &Adjacency, positions: Vec<usize>) -> Vec<AttributeKey> {
+    positions
+        .into_iter()
+        .map(|position| adjacency.node_indices[position].clone())
+        .collect()
+}
+
+fn checked_order(adjacency: &Adjacency, order: Vec<usize>) -> Result<Vec<usize>, PyGraphsterError> {
+    if order.len() < adjacency.node_count() {
+        let cycle = cycle_positions(adjacency).expect("Unsorted nodes must lie on a cycle");
+
+        return Err(cycle_error(&resolve(adjacency, cycle)));
+    }
+
+    Ok(order)
+}
+
+pub(crate) fn topological_positions(adjacency: &Adjacency) -> Result<Vec<usize>, PyGraphsterError> {
+    let mut in_degrees = in_degrees(adjacency);
+    let mut queue = (0..adjacency.node_count())
+        .filter(|position| in_degrees[*position] == 0)
+        .collect::<VecDeque<_>>();
+    let mut order = Vec::with_capacity(adjacency.node_count());
+
+    while let Some(position) = queue.pop_front() {
+        for &(neighbor, _) in &adjacency.outgoing[position] {
+            in_degrees[neighbor] -= 1;
+
+            if in_degrees[neighbor] == 0 {
+                queue.push_back(neighbor);
+            }
+        }
+
+        order.push(position);
+    }
+
+    checked_order(adjacency, order)
+}
+
+pub(crate) fn topological_sort(
+    view: &GraphView<'_>,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+    let order = topological_positions(&adjacency)?;
+
+    Ok(resolve(&adjacency, order))
+}
+
+fn value_rank(value: &AttributeValue) -> u8 {
+    match value {
+        AttributeValue::Boolean(_) => 0,
+        AttributeValue::String(_) => 2,
+        AttributeValue::Null => 3,
+        _ => 1,
+    }
+}
+
+#[derive(Debug)]
+struct SortEntry {
+    key: AttributeValue,
+    node_index: AttributeValue,
+    position: usize,
+}
+
+fn compare_values(left: &AttributeValue, right: &AttributeValue) -> Ordering {
+    compare_attributevalues(left, right).unwrap_or_else(|| value_rank(left).cmp(&value_rank(right)))
+}
+
+impl PartialEq for SortEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortEntry {}
+
+impl Ord for SortEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_values(&self.key, &other.key)
+            .then_with(|| compare_values(&self.node_index, &other.node_index))
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for SortEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub(crate) fn lexicographical_topological_sort(
+    view: &GraphView<'_>,
+    key: Option<&AttributeKey>,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+    let sort_entry = |position: usize| -> Result<Reverse<SortEntry>, PyGraphsterError> {
+        let node_index = &adjacency.node_indices[position];
+        let key = match key {
+            Some(key) => view
+                .node_attributes(node_index)?
+                .get(key)
+                .cloned()
+                .unwrap_or(AttributeValue::Null),
+            None => AttributeValue::Null,
+        };
+
+        Ok(Reverse(SortEntry {
+            key,
+            node_index: convert_attributekey_to_attributevalue(node_index.clone()),
+            position,
+        }))
+    };
+
+    let mut in_degrees = in_degrees(&adjacency);
+    let mut heap = (0..adjacency.node_count())
+        .filter(|position| in_degrees[*position] == 0)
+        .map(sort_entry)
+        .collect::<Result<BinaryHeap<_>, _>>()?;
+    let mut order = Vec::with_capacity(adjacency.node_count());
+
+    while let Some(Reverse(SortEntry { position, .. })) = heap.pop() {
+        for &(neighbor, _) in &adjacency.outgoing[position] {
+            in_degrees[neighbor] -= 1;
+
+            if in_degrees[neighbor] == 0 {
+                heap.push(sort_entry(neighbor)?);
+            }
+        }
+
+        order.push(position);
+    }
+
+    Ok(resolve(&adjacency, checked_order(&adjacency, order)?))
+}
+
+pub(crate) fn find_cycle(
+    view: &GraphView<'_>,
+) -> Result<Option<Vec<AttributeKey>>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, None)?;
+
+    Ok(cycle_positions(&adjacency).map(|cycle| resolve(&adjacency, cycle)))
+}
+
+pub(crate) fn is_dag(view: &GraphView<'_>) -> Result<bool, PyGraphsterError> {
+    Ok(find_cycle(view)?.is_none())
+}
+
+pub(crate) fn reachable(
+    view: &GraphView<'_>,
+    node_index: &AttributeKey,
+    direction: Direction,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    let mut traversal = Traversal::bfs(view, node_index.clone(), direction, None)?;
+    let mut node_indices = Vec::new();
+
+    traversal.next(view)?;
+
+    while let Some(event) = traversal.next(view)? {
+        node_indices.push(event.node_index);
+    }
+
+    Ok(node_indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::tests::{graph, index, node};
+    use std::collections::{HashMap, HashSet};
+
+    const EDGES: [(usize, usize, f64); 7] = [
+        (0, 1, 3.0),
+        (0, 2, 2.0),
+        (1, 3, 4.0),
+        (2, 3, 1.0),
+        (2, 4, 6.0),
+        (3, 5, 2.0),
+        (4, 5, 0.5),
+    ];
+
+    fn indices(node_indices: Vec<AttributeKey>) -> Vec<usize> {
+        node_indices.iter().map(index).collect()
+    }
+
+    #[test]
+    fn topological_sort_orders_every_edge() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        let positions = indices(topological_sort(&view).unwrap())
+            .into_iter()
+            .enumerate()
+            .map(|(position, node_index)| (node_index, position))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(positions.len(), 6);
+        for (source, target, _) in EDGES {
+            assert!(positions[&source] < positions[&target]);
+        }
+    }
+
+    #[test]
+    fn lexicographical_topological_sort_matches_networkx() {
+        let mut graph = graph(&EDGES);
+        let priority = AttributeKey::String("priority".to_string());
+
+        for node_index in 0..6 {
+            graph.node_attributes_mut(node(node_index)).unwrap().insert(
+                priority.clone(),
+                AttributeValue::Int64(-(node_index as i64)),
+            );
+        }
+
+        let view = GraphView::from(&graph);
+
+        assert_eq!(
+            indices(lexicographical_topological_sort(&view, None).unwrap()),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            indices(lexicographical_topological_sort(&view, Some(&priority)).unwrap()),
+            vec![0, 2, 4, 1, 3, 5]
+        );
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let dag = graph(&EDGES);
+        let view = GraphView::from(&dag);
+
+        assert!(is_dag(&view).unwrap());
+        assert_eq!(find_cycle(&view).unwrap(), None);
+
+        let mut edges = EDGES.to_vec();
+        edges.push((5, 2, 1.0));
+        let graph = graph(&edges);
+        let view = GraphView::from(&graph);
+
+        assert!(matches!(
+            topological_sort(&view),
+            Err(PyGraphsterError::Cycle(_))
+        ));
+        assert!(matches!(
+            lexicographical_topological_sort(&view, None),
+            Err(PyGraphsterError::Cycle(_))
+        ));
+        assert!(!is_dag(&view).unwrap());
+
+        let cycle = indices(find_cycle(&view).unwrap().unwrap());
+        let edges = edges
+            .iter()
+            .map(|&(source, target, _)| (source, target))
+            .collect::<HashSet<_>>();
+
+        assert!(!cycle.is_empty());
+        for (position, &source) in cycle.iter().enumerate() {
+            let target = cycle[(position + 1) % cycle.len()];
+
+            assert!(edges.contains(&(source, target)), "{:?}", cycle);
+        }
+    }
+}
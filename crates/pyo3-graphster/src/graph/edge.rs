@@ -4,9 +4,9 @@ use pyo3::{
     Bound, FromPyObject, IntoPy, PyAny, PyObject, PyRef, PyResult, Python,
 };
 
-use crate::{gil_hash_map::GILHashMap, Lut, PyGraphsterError, PyUsize};
+use crate::{datatypes::IntoPyNative, gil_hash_map::GILHashMap, Lut, PyGraphsterError, PyUsize};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct PyEdgeIndex(pub EdgeIndex);
 
@@ -34,7 +34,12 @@ fn convert_pyobject_to_edgeindex(ob: &Bound<'_, PyAny>) -> PyResult<EdgeIndex> {
     fn convert_int(ob: &Bound<'_, PyAny>) -> PyResult<EdgeIndex> {
         Ok(ob
             .extract::<usize>()
-            .expect("Extraction must succeed")
+            .map_err(|_| {
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert {} to EdgeIndex: value must be a non-negative integer that fits into usize",
+                    ob
+                )))
+            })?
             .into())
     }
 
@@ -49,7 +54,7 @@ fn convert_pyobject_to_edgeindex(ob: &Bound<'_, PyAny>) -> PyResult<EdgeIndex> {
     fn throw_error(ob: &Bound<'_, PyAny>) -> PyResult<EdgeIndex> {
         Err(
             PyGraphsterError::from(GraphsterError::ConversionError(format!(
-                "Could not convert {} into AttributeValue",
+                "Could not convert {} to EdgeIndex",
                 ob
             )))
             .into(),
@@ -86,3 +91,9 @@ impl IntoPy<PyObject> for PyEdgeIndex {
         PyUsize::new(self.0.into()).into_py(py)
     }
 }
+
+impl IntoPyNative for PyEdgeIndex {
+    fn into_py_native(self, py: Python<'_>) -> PyObject {
+        usize::from(self.0).into_py(py)
+    }
+}
@@ -0,0 +1,132 @@
+use super::{
+    adjacency::WeightSource,
+    astar::PyHeuristicInput,
+    centrality::{
+        betweenness_centrality, closeness_centrality, degree_centrality,
+        edge_betweenness_centrality, eigenvector_centrality, hits, katz_centrality,
+    },
+    components::{
+        component_values, condensation, is_strongly_connected, is_weakly_connected,
+        strongly_connected_components, weakly_connected_components,
+    },
+    components_to_python,
+    critical_path::{critical_path_analysis, dag_longest_path, PyCriticalPathAnalysis},
+    dag::{find_cycle, is_dag, lexicographical_topological_sort, reachable, topological_sort},
+    iterators::TraversalOutput,
+    neighbors::{degree, degrees, neighbors, Direction},
+    pagerank::{pagerank, Personalization, PyPersonalization},
+    shortest_paths::{all_pairs_shortest_path_lengths, shortest_path, shortest_path_lengths},
+    topology::{GraphView, ViewDefinition},
+    traversal::Traversal,
+    PyAttributes, PyDataGraph, PyEdgeIndex, PyEdgeIndexIterator, PyNodeIndex, PyNodeIndexIterator,
+    PyTraversalIterator,
+};
+use crate::{
+    datatypes::{extract_iterable, key::PyAttributeKey, IntoPyNative},
+    errors::PyGraphsterError,
+    query::PyQueryInput,
+};
+use graphster::datatypes::{AttributeKey, AttributeValue};
+use pyo3::{
+    pyclass, pymethods, Bound, IntoPy, Py, PyAny, PyClass, PyObject, PyRef, PyResult, Python,
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+
+#[pyclass]
+#[derive(Debug)]
+pub struct PyDataGraphView {
+    graph: Py<PyDataGraph>,
+    definition: ViewDefinition,
+}
+
+impl PyDataGraphView {
+    pub(crate) fn new(graph: Py<PyDataGraph>, definition: ViewDefinition) -> Self {
+        Self { graph, definition }
+    }
+}
+
+pub(crate) struct ViewHandle<'py> {
+    pub(crate) graph: PyRef<'py, PyDataGraph>,
+    pub(crate) definition: ViewDefinition,
+}
+
+impl ViewHandle<'_> {
+    pub(crate) fn view(&self) -> GraphView<'_> {
+        GraphView::new(&self.graph.graph, &self.definition)
+    }
+
+    pub(crate) fn native_types(&self) -> bool {
+        self.graph.native_types
+    }
+
+    pub(crate) fn neighbors(
+        &self,
+        node_index: AttributeKey,
+        direction: Direction,
+    ) -> PyResult<PyNodeIndexIterator> {
+        let node_indices = neighbors(&self.view(), &node_index, direction)?;
+
+        Ok(PyNodeIndexIterator::new(node_indices, self.native_types()))
+    }
+
+    pub(crate) fn degree(&self, node_index: AttributeKey, direction: Direction) -> PyResult<usize> {
+        Ok(degree(&self.view(), &node_index, direction)?)
+    }
+
+    pub(crate) fn degrees(
+        &self,
+        node_indices: Option<Vec<PyNodeIndex>>,
+        direction: Direction,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let node_indices = node_indices
+            .map(|node_indices| node_indices.into_iter().map(AttributeKey::from).collect());
+
+        Ok(degrees(&self.view(), node_indices, direction)?
+            .into_iter()
+            .map(|(node_index, node_degree)| (PyNodeIndex::from(node_index), node_degree))
+            .collect::<HashMap<_, _>>()
+            .into_py_with_mode(py, self.native_types()))
+    }
+}
+
+pub(crate) trait GraphSource: PyClass {
+    fn data_graph<'py>(slf: &Bound<'py, Self>) -> Bound<'py, PyDataGraph>;
+
+    fn definition(slf: &Bound<'_, Self>) -> ViewDefinition;
+
+    fn handle<'py>(slf: &Bound<'py, Self>) -> ViewHandle<'py> {
+        ViewHandle {
+            graph: Self::data_graph(slf).borrow(),
+            definition: Self::definition(slf),
+        }
+    }
+
+    fn write_node_attribute_values(
+        slf: &Bound<'_, Self>,
+        key: AttributeKey,
+        values: Vec<(AttributeKey, AttributeValue)>,
+    ) -> PyResult<()> {
+        Self::data_graph(slf)
+            .try_borrow_mut()?
+            .set_node_attribute_values(key, values)
+    }
+}
+
+impl GraphSource for PyDataGraphView {
+    fn data_graph<'py>(slf: &Bound<'py, Self>) -> Bound<'py, PyDataGraph> {
+        slf.borrow().graph.bind(slf.py()).clone()
+    }
+
+    fn definition(slf: &Bound<'_, Self>) -> ViewDefinition {
+        slf.borrow().definition.clone()
+    }
+}
+
+implement_graph_pymethods!(PyDataGraphView, {
+    #[getter]
+    fn graph(&self, py: Python<'_>) -> Py<PyDataGraph> {
+        self.graph.clone_ref(py)
+    }
+});
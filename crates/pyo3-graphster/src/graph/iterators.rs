@@ -0,0 +1,337 @@
+use super::{
+    graph_view::ViewHandle,
+    topology::{GraphView, ViewDefinition},
+    traversal::{Traversal, TraversalEventKind},
+    PyAttributes, PyDataGraph, PyEdgeIndex, PyNodeIndex,
+};
+use crate::{datatypes::IntoPyNative, errors::PyGraphsterError};
+use graphster::{datatypes::AttributeKey, graph::EdgeIndex};
+use pyo3::{
+    exceptions::PyRuntimeError, pyclass, pymethods, Bound, IntoPy, Py, PyErr, PyObject, PyRef,
+    PyResult, Python,
+};
+use std::vec::IntoIter;
+
+fn graph_changed_error() -> PyErr {
+    PyRuntimeError::new_err("PyDataGraph changed during iteration")
+}
+
+enum Indices<T> {
+    Tracked {
+        indices: IntoIter<T>,
+        graph: Py<PyDataGraph>,
+        generation: usize,
+    },
+    Snapshot(IntoIter<T>),
+}
+
+impl<T> Indices<T> {
+    fn tracked(graph: PyRef<'_, PyDataGraph>, indices: Vec<T>) -> Self {
+        Self::Tracked {
+            indices: indices.into_iter(),
+            generation: graph.generation,
+            graph: graph.into(),
+        }
+    }
+
+    fn next(&mut self, py: Python<'_>) -> PyResult<Option<T>> {
+        match self {
+            Self::Tracked {
+                indices,
+                graph,
+                generation,
+            } => {
+                if graph.borrow(py).generation != *generation {
+                    return Err(graph_changed_error());
+                }
+
+                Ok(indices.next())
+            }
+            Self::Snapshot(indices) => Ok(indices.next()),
+        }
+    }
+
+    fn length_hint(&self) -> usize {
+        match self {
+            Self::Tracked { indices, .. } | Self::Snapshot(indices) => indices.len(),
+        }
+    }
+}
+
+#[pyclass]
+pub struct PyNodeIndexIterator {
+    node_indices: Indices<AttributeKey>,
+    native_types: bool,
+}
+
+impl PyNodeIndexIterator {
+    pub(crate) fn new(node_indices: Vec<AttributeKey>, native_types: bool) -> Self {
+        Self {
+            node_indices: Indices::Snapshot(node_indices.into_iter()),
+            native_types,
+        }
+    }
+
+    pub(crate) fn tracked(graph: PyRef<'_, PyDataGraph>, node_indices: Vec<AttributeKey>) -> Self {
+        Self {
+            native_types: graph.native_types,
+            node_indices: Indices::tracked(graph, node_indices),
+        }
+    }
+}
+
+#[pymethods]
+impl PyNodeIndexIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        Ok(self.node_indices.next(py)?.map(|node_index| {
+            PyNodeIndex::from(node_index).into_py_with_mode(py, self.native_types)
+        }))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.node_indices.length_hint()
+    }
+}
+
+#[pyclass]
+pub struct PyEdgeIndexIterator {
+    edge_indices: Indices<EdgeIndex>,
+    native_types: bool,
+}
+
+impl PyEdgeIndexIterator {
+    pub(crate) fn new(edge_indices: Vec<EdgeIndex>, native_types: bool) -> Self {
+        Self {
+            edge_indices: Indices::Snapshot(edge_indices.into_iter()),
+            native_types,
+        }
+    }
+
+    pub(crate) fn tracked(graph: PyRef<'_, PyDataGraph>, edge_indices: Vec<EdgeIndex>) -> Self {
+        Self {
+            native_types: graph.native_types,
+            edge_indices: Indices::tracked(graph, edge_indices),
+        }
+    }
+}
+
+#[pymethods]
+impl PyEdgeIndexIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        Ok(self.edge_indices.next(py)?.map(|edge_index| {
+            PyEdgeIndex::from(edge_index).into_py_with_mode(py, self.native_types)
+        }))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.edge_indices.length_hint()
+    }
+}
+
+#[pyclass]
+pub struct PyNodeDataIterator {
+    node_indices: Indices<AttributeKey>,
+    graph: Py<PyDataGraph>,
+    native_types: bool,
+}
+
+impl PyNodeDataIterator {
+    pub(crate) fn new(graph: Bound<'_, PyDataGraph>) -> PyResult<Self> {
+        let borrowed = graph.borrow();
+        let node_indices = borrowed
+            .graph
+            .node_indices()
+            .map(|node_index| node_index.0.clone())
+            .collect();
+
+        Ok(Self {
+            native_types: borrowed.native_types,
+            node_indices: Indices::tracked(borrowed, node_indices),
+            graph: graph.unbind(),
+        })
+    }
+}
+
+#[pymethods]
+impl PyNodeDataIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let Some(node_index) = self.node_indices.next(py)? else {
+            return Ok(None);
+        };
+
+        let attributes = self
+            .graph
+            .borrow(py)
+            .graph
+            .node_attributes(node_index.clone())
+            .map_err(PyGraphsterError::from)?
+            .iter()
+            .map(|(key, value)| (key.clone().into(), value.clone().into()))
+            .collect::<PyAttributes>();
+
+        Ok(Some(
+            (PyNodeIndex::from(node_index), attributes).into_py_with_mode(py, self.native_types),
+        ))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.node_indices.length_hint()
+    }
+}
+
+#[pyclass]
+pub struct PyEdgeDataIterator {
+    edge_indices: Indices<EdgeIndex>,
+    graph: Py<PyDataGraph>,
+    native_types: bool,
+}
+
+impl PyEdgeDataIterator {
+    pub(crate) fn new(graph: Bound<'_, PyDataGraph>) -> PyResult<Self> {
+        let borrowed = graph.borrow();
+        let edge_indices = borrowed.graph.edge_indices().copied().collect();
+
+        Ok(Self {
+            native_types: borrowed.native_types,
+            edge_indices: Indices::tracked(borrowed, edge_indices),
+            graph: graph.unbind(),
+        })
+    }
+}
+
+#[pymethods]
+impl PyEdgeDataIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let Some(edge_index) = self.edge_indices.next(py)? else {
+            return Ok(None);
+        };
+
+        let attributes = self
+            .graph
+            .borrow(py)
+            .graph
+            .edge_attributes(&edge_index)
+            .map_err(PyGraphsterError::from)?
+            .iter()
+            .map(|(key, value)| (key.clone().into(), value.clone().into()))
+            .collect::<PyAttributes>();
+
+        Ok(Some(
+            (PyEdgeIndex::from(edge_index), attributes).into_py_with_mode(py, self.native_types),
+        ))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.edge_indices.length_hint()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraversalOutput {
+    Nodes,
+    Details,
+    Events,
+}
+
+impl TraversalOutput {
+    pub(crate) fn new(with_details: bool, with_events: bool) -> Self {
+        if with_events {
+            Self::Events
+        } else if with_details {
+            Self::Details
+        } else {
+            Self::Nodes
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Debug)]
+pub struct PyTraversalIterator {
+    graph: Py<PyDataGraph>,
+    generation: usize,
+    definition: ViewDefinition,
+    traversal: Traversal,
+    output: TraversalOutput,
+}
+
+impl PyTraversalIterator {
+    pub(crate) fn new(
+        handle: ViewHandle<'_>,
+        traversal: Traversal,
+        output: TraversalOutput,
+    ) -> Self {
+        let ViewHandle { graph, definition } = handle;
+
+        Self {
+            generation: graph.generation,
+            graph: graph.into(),
+            definition,
+            traversal,
+            output,
+        }
+    }
+}
+
+#[pymethods]
+impl PyTraversalIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let graph = self.graph.borrow(py);
+
+        if graph.generation != self.generation {
+            return Err(graph_changed_error());
+        }
+
+        let view = GraphView::new(&graph.graph, &self.definition);
+        let event = loop {
+            match self.traversal.next(&view)? {
+                Some(event)
+                    if self.output != TraversalOutput::Events
+                        && event.kind == TraversalEventKind::Finish => {}
+                Some(event) => break event,
+                None => return Ok(None),
+            }
+        };
+
+        let node_index =
+            PyNodeIndex::from(event.node_index).into_py_with_mode(py, graph.native_types);
+
+        if self.output == TraversalOutput::Nodes {
+            return Ok(Some(node_index));
+        }
+
+        let parent = event
+            .parent
+            .map(|parent| PyNodeIndex::from(parent).into_py_with_mode(py, graph.native_types));
+
+        Ok(Some(match (self.output, event.kind) {
+            (TraversalOutput::Events, TraversalEventKind::Discover) => {
+                ("discover", node_index, event.depth, parent).into_py(py)
+            }
+            (TraversalOutput::Events, TraversalEventKind::Finish) => {
+                ("finish", node_index, event.depth, parent).into_py(py)
+            }
+            _ => (node_index, event.depth, parent).into_py(py),
+        }))
+    }
+}
@@ -0,0 +1,801 @@
+macro_rules! implement_graph_pymethods {
+    ($struct:ident, { $($methods:tt)* }) => {
+        #[pymethods]
+        impl $struct {
+            fn reversed_view(slf: &Bound<'_, Self>) -> PyDataGraphView {
+                PyDataGraphView::new(
+                    Self::data_graph(slf).unbind(),
+                    Self::definition(slf).reversed(),
+                )
+            }
+
+            fn undirected_view(slf: &Bound<'_, Self>) -> PyDataGraphView {
+                PyDataGraphView::new(
+                    Self::data_graph(slf).unbind(),
+                    Self::definition(slf).undirected(),
+                )
+            }
+
+            #[pyo3(signature = (node_query = None, edge_query = None))]
+            fn filtered_view(
+                slf: &Bound<'_, Self>,
+                node_query: Option<PyQueryInput>,
+                edge_query: Option<PyQueryInput>,
+            ) -> PyResult<PyDataGraphView> {
+                let node_predicate = node_query.map(PyQueryInput::into_predicate).transpose()?;
+                let edge_predicate = edge_query.map(PyQueryInput::into_predicate).transpose()?;
+                let handle = Self::handle(slf);
+                let definition = handle.definition.filtered(
+                    &handle.graph.graph,
+                    node_predicate,
+                    edge_predicate,
+                )?;
+
+                Ok(PyDataGraphView::new(
+                    Self::data_graph(slf).unbind(),
+                    definition,
+                ))
+            }
+
+            fn node_count(slf: &Bound<'_, Self>) -> usize {
+                Self::handle(slf).view().node_count()
+            }
+
+            fn edge_count(slf: &Bound<'_, Self>) -> usize {
+                Self::handle(slf).view().edge_count()
+            }
+
+            fn node_indices(slf: &Bound<'_, Self>) -> PyNodeIndexIterator {
+                let handle = Self::handle(slf);
+                let node_indices = handle.view().node_indices();
+
+                PyNodeIndexIterator::tracked(handle.graph, node_indices)
+            }
+
+            fn edge_indices(slf: &Bound<'_, Self>) -> PyEdgeIndexIterator {
+                let handle = Self::handle(slf);
+                let edge_indices = handle.view().edge_indices();
+
+                PyEdgeIndexIterator::tracked(handle.graph, edge_indices)
+            }
+
+            fn contains_node(slf: &Bound<'_, Self>, node_index: PyNodeIndex) -> bool {
+                Self::handle(slf).view().contains_node(&node_index.into())
+            }
+
+            fn contains_edge(slf: &Bound<'_, Self>, edge_index: PyEdgeIndex) -> bool {
+                Self::handle(slf).view().contains_edge(&edge_index.0)
+            }
+
+            fn node_attributes(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+
+                Ok(handle
+                    .view()
+                    .node_attributes(&node_index.into())?
+                    .into_par_iter()
+                    .map(|(key, value)| (key.clone().into(), value.clone().into()))
+                    .collect::<PyAttributes>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            fn edge_attributes(
+                slf: &Bound<'_, Self>,
+                edge_index: PyEdgeIndex,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+
+                Ok(handle
+                    .view()
+                    .edge_attributes(&edge_index.0)?
+                    .into_par_iter()
+                    .map(|(key, value)| (key.clone().into(), value.clone().into()))
+                    .collect::<PyAttributes>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            fn edge_endpoints(
+                slf: &Bound<'_, Self>,
+                edge_index: PyEdgeIndex,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let (source_node_index, target_node_index) =
+                    handle.view().edge_endpoints(&edge_index.0)?;
+
+                Ok((
+                    PyNodeIndex::from(source_node_index.clone()),
+                    PyNodeIndex::from(target_node_index.clone()),
+                )
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            fn edge_endpoints_many(
+                slf: &Bound<'_, Self>,
+                edge_indices: &Bound<'_, PyAny>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let edge_indices = extract_iterable::<PyEdgeIndex>(edge_indices)?;
+                let handle = Self::handle(slf);
+                let view = handle.view();
+
+                Ok(edge_indices
+                    .into_par_iter()
+                    .map(|edge_index| {
+                        let (source_node_index, target_node_index) =
+                            view.edge_endpoints(&edge_index.0)?;
+
+                        Ok((
+                            PyNodeIndex::from(source_node_index.clone()),
+                            PyNodeIndex::from(target_node_index.clone()),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, PyGraphsterError>>()?
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            fn select_nodes(
+                slf: &Bound<'_, Self>,
+                query: PyQueryInput,
+            ) -> PyResult<PyNodeIndexIterator> {
+                let predicate = query.into_predicate()?;
+                let handle = Self::handle(slf);
+                let node_indices = handle.view().select_nodes(&predicate)?;
+
+                Ok(PyNodeIndexIterator::new(
+                    node_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            fn select_edges(
+                slf: &Bound<'_, Self>,
+                query: PyQueryInput,
+            ) -> PyResult<PyEdgeIndexIterator> {
+                let predicate = query.into_predicate()?;
+                let handle = Self::handle(slf);
+                let edge_indices = handle.view().select_edges(&predicate)?;
+
+                Ok(PyEdgeIndexIterator::new(
+                    edge_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            fn successors(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+            ) -> PyResult<PyNodeIndexIterator> {
+                Self::handle(slf).neighbors(node_index.into(), Direction::Outgoing)
+            }
+
+            fn predecessors(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+            ) -> PyResult<PyNodeIndexIterator> {
+                Self::handle(slf).neighbors(node_index.into(), Direction::Incoming)
+            }
+
+            fn neighbors(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+            ) -> PyResult<PyNodeIndexIterator> {
+                Self::handle(slf).neighbors(node_index.into(), Direction::Both)
+            }
+
+            fn in_degree(slf: &Bound<'_, Self>, node_index: PyNodeIndex) -> PyResult<usize> {
+                Self::handle(slf).degree(node_index.into(), Direction::Incoming)
+            }
+
+            fn out_degree(slf: &Bound<'_, Self>, node_index: PyNodeIndex) -> PyResult<usize> {
+                Self::handle(slf).degree(node_index.into(), Direction::Outgoing)
+            }
+
+            fn degree(slf: &Bound<'_, Self>, node_index: PyNodeIndex) -> PyResult<usize> {
+                Self::handle(slf).degree(node_index.into(), Direction::Both)
+            }
+
+            #[pyo3(signature = (node_indices = None))]
+            fn in_degrees(
+                slf: &Bound<'_, Self>,
+                node_indices: Option<Vec<PyNodeIndex>>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                Self::handle(slf).degrees(node_indices, Direction::Incoming, py)
+            }
+
+            #[pyo3(signature = (node_indices = None))]
+            fn out_degrees(
+                slf: &Bound<'_, Self>,
+                node_indices: Option<Vec<PyNodeIndex>>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                Self::handle(slf).degrees(node_indices, Direction::Outgoing, py)
+            }
+
+            #[pyo3(signature = (node_indices = None))]
+            fn degrees(
+                slf: &Bound<'_, Self>,
+                node_indices: Option<Vec<PyNodeIndex>>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                Self::handle(slf).degrees(node_indices, Direction::Both, py)
+            }
+
+            #[pyo3(signature = (start, direction = Direction::Outgoing, max_depth = None, with_details = false))]
+            fn bfs(
+                slf: &Bound<'_, Self>,
+                start: PyNodeIndex,
+                direction: Direction,
+                max_depth: Option<usize>,
+                with_details: bool,
+            ) -> PyResult<PyTraversalIterator> {
+                let handle = Self::handle(slf);
+                let traversal = Traversal::bfs(&handle.view(), start.into(), direction, max_depth)?;
+
+                Ok(PyTraversalIterator::new(
+                    handle,
+                    traversal,
+                    TraversalOutput::new(with_details, false),
+                ))
+            }
+
+            #[pyo3(signature = (
+                start,
+                direction = Direction::Outgoing,
+                max_depth = None,
+                with_details = false,
+                with_events = false
+            ))]
+            fn dfs(
+                slf: &Bound<'_, Self>,
+                start: PyNodeIndex,
+                direction: Direction,
+                max_depth: Option<usize>,
+                with_details: bool,
+                with_events: bool,
+            ) -> PyResult<PyTraversalIterator> {
+                let handle = Self::handle(slf);
+                let traversal = Traversal::dfs(&handle.view(), start.into(), direction, max_depth)?;
+
+                Ok(PyTraversalIterator::new(
+                    handle,
+                    traversal,
+                    TraversalOutput::new(with_details, with_events),
+                ))
+            }
+
+            #[pyo3(signature = (source, target, weight = None))]
+            fn shortest_path(
+                slf: &Bound<'_, Self>,
+                source: PyNodeIndex,
+                target: PyNodeIndex,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+
+                Ok(shortest_path(
+                    &handle.view(),
+                    &source.into(),
+                    &target.into(),
+                    weight.as_ref(),
+                )?
+                .map(|path| path.into_iter().map(PyNodeIndex::from).collect::<Vec<_>>())
+                .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (source, weight = None))]
+            fn shortest_path_lengths(
+                slf: &Bound<'_, Self>,
+                source: PyNodeIndex,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+
+                Ok(
+                    shortest_path_lengths(&handle.view(), &source.into(), weight.as_ref())?
+                        .into_iter()
+                        .map(|(node_index, length)| (PyNodeIndex::from(node_index), length))
+                        .collect::<HashMap<_, _>>()
+                        .into_py_with_mode(py, handle.native_types()),
+                )
+            }
+
+            #[pyo3(signature = (weight = None))]
+            fn all_pairs_shortest_path_lengths(
+                slf: &Bound<'_, Self>,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+
+                Ok(
+                    all_pairs_shortest_path_lengths(&handle.view(), weight.as_ref())?
+                        .into_iter()
+                        .map(|(source, lengths)| {
+                            (
+                                PyNodeIndex::from(source),
+                                lengths
+                                    .into_iter()
+                                    .map(|(target, length)| (PyNodeIndex::from(target), length))
+                                    .collect::<HashMap<_, _>>(),
+                            )
+                        })
+                        .collect::<HashMap<_, _>>()
+                        .into_py_with_mode(py, handle.native_types()),
+                )
+            }
+
+            #[pyo3(signature = (source, target, weight = None, heuristic = None))]
+            fn astar_path(
+                slf: &Bound<'_, Self>,
+                source: PyNodeIndex,
+                target: PyNodeIndex,
+                weight: Option<PyAttributeKey>,
+                heuristic: Option<PyHeuristicInput>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let view = handle.view();
+                let (source, target) = (source.into(), target.into());
+                let weight = weight.map(AttributeKey::from);
+
+                let path = match heuristic {
+                    Some(heuristic) => heuristic.astar_path(
+                        &view,
+                        &source,
+                        &target,
+                        weight.as_ref(),
+                        handle.native_types(),
+                        py,
+                    )?,
+                    None => shortest_path(&view, &source, &target, weight.as_ref())?,
+                };
+
+                Ok(path
+                    .map(|path| path.into_iter().map(PyNodeIndex::from).collect::<Vec<_>>())
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (attribute = None))]
+            fn weakly_connected_components(
+                slf: &Bound<'_, Self>,
+                attribute: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let components = weakly_connected_components(&Self::handle(slf).view())?;
+
+                if let Some(attribute) = attribute {
+                    Self::write_node_attribute_values(
+                        slf,
+                        attribute.into(),
+                        component_values(&components),
+                    )?;
+                }
+
+                Ok(components_to_python(
+                    components,
+                    Self::handle(slf).native_types(),
+                    py,
+                ))
+            }
+
+            #[pyo3(signature = (attribute = None))]
+            fn strongly_connected_components(
+                slf: &Bound<'_, Self>,
+                attribute: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let components = strongly_connected_components(&Self::handle(slf).view())?;
+
+                if let Some(attribute) = attribute {
+                    Self::write_node_attribute_values(
+                        slf,
+                        attribute.into(),
+                        component_values(&components),
+                    )?;
+                }
+
+                Ok(components_to_python(
+                    components,
+                    Self::handle(slf).native_types(),
+                    py,
+                ))
+            }
+
+            fn is_weakly_connected(slf: &Bound<'_, Self>) -> PyResult<bool> {
+                Ok(is_weakly_connected(&Self::handle(slf).view())?)
+            }
+
+            fn is_strongly_connected(slf: &Bound<'_, Self>) -> PyResult<bool> {
+                Ok(is_strongly_connected(&Self::handle(slf).view())?)
+            }
+
+            #[pyo3(signature = (attribute = None))]
+            fn condensation(
+                slf: &Bound<'_, Self>,
+                attribute: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let (graph, components) = condensation(&Self::handle(slf).view())?;
+
+                if let Some(attribute) = attribute {
+                    Self::write_node_attribute_values(
+                        slf,
+                        attribute.into(),
+                        component_values(&components),
+                    )?;
+                }
+
+                let native_types = Self::handle(slf).native_types();
+
+                let members = components
+                    .into_iter()
+                    .enumerate()
+                    .map(|(component_id, component)| {
+                        (
+                            PyNodeIndex::from(AttributeKey::Usize(component_id)),
+                            component
+                                .into_iter()
+                                .map(PyNodeIndex::from)
+                                .collect::<Vec<_>>(),
+                        )
+                    })
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, native_types);
+                let graph = Py::new(
+                    py,
+                    PyDataGraph {
+                        native_types,
+                        ..graph.into()
+                    },
+                )?;
+
+                Ok((graph, members).into_py(py))
+            }
+
+            fn topological_sort(slf: &Bound<'_, Self>) -> PyResult<PyNodeIndexIterator> {
+                let handle = Self::handle(slf);
+                let node_indices = topological_sort(&handle.view())?;
+
+                Ok(PyNodeIndexIterator::new(
+                    node_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            #[pyo3(signature = (key = None))]
+            fn lexicographical_topological_sort(
+                slf: &Bound<'_, Self>,
+                key: Option<PyAttributeKey>,
+            ) -> PyResult<PyNodeIndexIterator> {
+                let handle = Self::handle(slf);
+                let key = key.map(AttributeKey::from);
+                let node_indices = lexicographical_topological_sort(&handle.view(), key.as_ref())?;
+
+                Ok(PyNodeIndexIterator::new(
+                    node_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            fn find_cycle(slf: &Bound<'_, Self>, py: Python<'_>) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+
+                Ok(find_cycle(&handle.view())?
+                    .map(|cycle| cycle.into_iter().map(PyNodeIndex::from).collect::<Vec<_>>())
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            fn is_dag(slf: &Bound<'_, Self>) -> PyResult<bool> {
+                Ok(is_dag(&Self::handle(slf).view())?)
+            }
+
+            fn ancestors(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+            ) -> PyResult<PyNodeIndexIterator> {
+                let handle = Self::handle(slf);
+                let node_indices =
+                    reachable(&handle.view(), &node_index.into(), Direction::Incoming)?;
+
+                Ok(PyNodeIndexIterator::new(
+                    node_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            fn descendants(
+                slf: &Bound<'_, Self>,
+                node_index: PyNodeIndex,
+            ) -> PyResult<PyNodeIndexIterator> {
+                let handle = Self::handle(slf);
+                let node_indices =
+                    reachable(&handle.view(), &node_index.into(), Direction::Outgoing)?;
+
+                Ok(PyNodeIndexIterator::new(
+                    node_indices,
+                    handle.native_types(),
+                ))
+            }
+
+            #[pyo3(signature = (weight = None, weight_source = WeightSource::Edge))]
+            fn dag_longest_path(
+                slf: &Bound<'_, Self>,
+                weight: Option<PyAttributeKey>,
+                weight_source: WeightSource,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+
+                Ok(
+                    dag_longest_path(&handle.view(), weight.as_ref(), weight_source)?
+                        .into_iter()
+                        .map(PyNodeIndex::from)
+                        .collect::<Vec<_>>()
+                        .into_py_with_mode(py, handle.native_types()),
+                )
+            }
+
+            #[pyo3(signature = (weight = None, weight_source = WeightSource::Edge, attribute_prefix = None))]
+            fn critical_path_analysis(
+                slf: &Bound<'_, Self>,
+                weight: Option<PyAttributeKey>,
+                weight_source: WeightSource,
+                attribute_prefix: Option<&str>,
+                py: Python<'_>,
+            ) -> PyResult<PyCriticalPathAnalysis> {
+                let weight = weight.map(AttributeKey::from);
+                let analysis = critical_path_analysis(
+                    &Self::handle(slf).view(),
+                    weight.as_ref(),
+                    weight_source,
+                )?;
+                if let Some(attribute_prefix) = attribute_prefix {
+                    for (key, values) in analysis.attribute_values(attribute_prefix) {
+                        Self::write_node_attribute_values(slf, key, values)?;
+                    }
+                }
+
+                Ok(PyCriticalPathAnalysis::new(
+                    analysis,
+                    Self::handle(slf).native_types(),
+                    py,
+                ))
+            }
+
+            #[pyo3(signature = (
+                alpha = 0.85,
+                weight = None,
+                personalization = None,
+                tol = 1e-6,
+                max_iter = 100
+            ))]
+            fn pagerank(
+                slf: &Bound<'_, Self>,
+                alpha: f64,
+                weight: Option<PyAttributeKey>,
+                personalization: Option<PyPersonalization>,
+                tol: f64,
+                max_iter: usize,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let personalization = personalization.map(Personalization::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| {
+                        pagerank(
+                            &view,
+                            alpha,
+                            weight.as_ref(),
+                            personalization.as_ref(),
+                            tol,
+                            max_iter,
+                        )
+                    })?
+                    .into_iter()
+                    .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (direction = Direction::Both, weight = None))]
+            fn degree_centrality(
+                slf: &Bound<'_, Self>,
+                direction: Direction,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+
+                Ok(
+                    degree_centrality(&handle.view(), direction, weight.as_ref())?
+                        .into_iter()
+                        .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                        .collect::<HashMap<_, _>>()
+                        .into_py_with_mode(py, handle.native_types()),
+                )
+            }
+
+            #[pyo3(signature = (weight = None, wf_improved = true))]
+            fn closeness_centrality(
+                slf: &Bound<'_, Self>,
+                weight: Option<PyAttributeKey>,
+                wf_improved: bool,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| closeness_centrality(&view, weight.as_ref(), wf_improved))?
+                    .into_iter()
+                    .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (k = None, normalized = true, weight = None, endpoints = false, seed = None))]
+            fn betweenness_centrality(
+                slf: &Bound<'_, Self>,
+                k: Option<usize>,
+                normalized: bool,
+                weight: Option<PyAttributeKey>,
+                endpoints: bool,
+                seed: Option<u64>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| {
+                        betweenness_centrality(
+                            &view,
+                            k,
+                            normalized,
+                            weight.as_ref(),
+                            endpoints,
+                            seed,
+                        )
+                    })?
+                    .into_iter()
+                    .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (k = None, normalized = true, weight = None, seed = None))]
+            fn edge_betweenness_centrality(
+                slf: &Bound<'_, Self>,
+                k: Option<usize>,
+                normalized: bool,
+                weight: Option<PyAttributeKey>,
+                seed: Option<u64>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| {
+                        edge_betweenness_centrality(&view, k, normalized, weight.as_ref(), seed)
+                    })?
+                    .into_iter()
+                    .map(|(edge_index, score)| (PyEdgeIndex::from(edge_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (max_iter = 100, tol = 1e-6, weight = None))]
+            fn eigenvector_centrality(
+                slf: &Bound<'_, Self>,
+                max_iter: usize,
+                tol: f64,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| {
+                        eigenvector_centrality(&view, max_iter, tol, weight.as_ref())
+                    })?
+                    .into_iter()
+                    .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (
+                alpha = 0.1,
+                beta = 1.0,
+                max_iter = 1000,
+                tol = 1e-6,
+                normalized = true,
+                weight = None
+            ))]
+            #[allow(clippy::too_many_arguments)]
+            fn katz_centrality(
+                slf: &Bound<'_, Self>,
+                alpha: f64,
+                beta: f64,
+                max_iter: usize,
+                tol: f64,
+                normalized: bool,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+
+                Ok(py
+                    .allow_threads(|| {
+                        katz_centrality(
+                            &view,
+                            alpha,
+                            beta,
+                            max_iter,
+                            tol,
+                            normalized,
+                            weight.as_ref(),
+                        )
+                    })?
+                    .into_iter()
+                    .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                    .collect::<HashMap<_, _>>()
+                    .into_py_with_mode(py, handle.native_types()))
+            }
+
+            #[pyo3(signature = (max_iter = 100, tol = 1e-8, normalized = true, weight = None))]
+            fn hits(
+                slf: &Bound<'_, Self>,
+                max_iter: usize,
+                tol: f64,
+                normalized: bool,
+                weight: Option<PyAttributeKey>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let handle = Self::handle(slf);
+                let weight = weight.map(AttributeKey::from);
+                let view = handle.view();
+                let (hubs, authorities) =
+                    py.allow_threads(|| hits(&view, max_iter, tol, normalized, weight.as_ref()))?;
+                let scores = |scores: Vec<(AttributeKey, f64)>| {
+                    scores
+                        .into_iter()
+                        .map(|(node_index, score)| (PyNodeIndex::from(node_index), score))
+                        .collect::<HashMap<_, _>>()
+                };
+
+                Ok(
+                    (scores(hubs), scores(authorities))
+                        .into_py_with_mode(py, handle.native_types()),
+                )
+            }
+
+            $($methods)*
+        }
+    };
+}
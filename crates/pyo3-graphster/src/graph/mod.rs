@@ -1,52 +1,311 @@
+#[macro_use]
+mod methods;
+
+mod adjacency;
+mod astar;
+mod centrality;
+mod components;
+mod critical_path;
+mod dag;
 mod edge;
+mod graph_view;
+mod iterators;
+mod neighbors;
+mod pagerank;
+mod shortest_paths;
+mod subgraph;
+mod topology;
+mod traversal;
+mod views;
 
-use crate::{datatypes::key::PyAttributeKey, errors::PyGraphsterError, PyAttributeValue};
+#[cfg(feature = "polars")]
+use crate::dataframe::{
+    convert_dataframe_to_edge_values, convert_dataframe_to_edges, convert_dataframe_to_node_values,
+    convert_dataframe_to_nodes, convert_edges_to_dataframe, convert_node_values_to_dataframe,
+    convert_nodes_to_dataframe,
+};
+use crate::{
+    datatypes::{extract_iterable, key::PyAttributeKey, IntoPyNative},
+    errors::PyGraphsterError,
+    query::PyQueryInput,
+    PyAttributeValue,
+};
+use adjacency::WeightSource;
+pub use astar::PyHeuristic;
+use astar::PyHeuristicInput;
+use centrality::{
+    betweenness_centrality, closeness_centrality, degree_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, hits, katz_centrality,
+};
+use components::{
+    component_values, condensation, is_strongly_connected, is_weakly_connected,
+    strongly_connected_components, weakly_connected_components,
+};
+pub use critical_path::PyCriticalPathAnalysis;
+use critical_path::{critical_path_analysis, dag_longest_path};
+use dag::{find_cycle, is_dag, lexicographical_topological_sort, reachable, topological_sort};
 pub use edge::PyEdgeIndex;
-use graphster::graph::DataGraph;
-use pyo3::{pyclass, pymethods, PyResult};
-use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use std::collections::HashMap;
+use graph_view::GraphSource;
+pub use graph_view::PyDataGraphView;
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    errors::GraphsterError,
+    graph::{DataGraph, EdgeIndex},
+};
+use iterators::TraversalOutput;
+pub use iterators::{
+    PyEdgeDataIterator, PyEdgeIndexIterator, PyNodeDataIterator, PyNodeIndexIterator,
+    PyTraversalIterator,
+};
+use neighbors::Direction;
+use pagerank::{pagerank, Personalization, PyPersonalization};
+use pyo3::{pyclass, pymethods, Bound, IntoPy, Py, PyAny, PyObject, PyRef, PyResult, Python};
+#[cfg(feature = "polars")]
+use pyo3_polars::PyDataFrame;
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use shortest_paths::{all_pairs_shortest_path_lengths, shortest_path, shortest_path_lengths};
+use std::collections::{HashMap, HashSet};
+use subgraph::{edge_subgraph, subgraph};
+use topology::ViewDefinition;
+use traversal::Traversal;
+pub use views::{PyEdgesView, PyNodesView};
+
+type Attributes = HashMap<AttributeKey, AttributeValue>;
 
 pub type PyAttributes = HashMap<PyAttributeKey, PyAttributeValue>;
 pub type PyNodeIndex = PyAttributeKey;
 
 #[pyclass]
 #[derive(Debug)]
-#[repr(transparent)]
-pub struct PyDataGraph(DataGraph);
+pub struct PyDataGraph {
+    graph: DataGraph,
+    #[pyo3(get, set)]
+    native_types: bool,
+    generation: usize,
+}
 
-#[allow(clippy::new_without_default)]
-#[pymethods]
 impl PyDataGraph {
+    fn mutate_structure<T>(
+        &mut self,
+        mutation: impl FnOnce(&mut DataGraph) -> Result<T, GraphsterError>,
+    ) -> Result<T, PyGraphsterError> {
+        let counts = (self.graph.node_count(), self.graph.edge_count());
+        let result = mutation(&mut self.graph);
+
+        if result.is_ok() || counts != (self.graph.node_count(), self.graph.edge_count()) {
+            self.generation += 1;
+        }
+
+        Ok(result?)
+    }
+
+    fn rewire(
+        &mut self,
+        edge_index: EdgeIndex,
+        source_node_index: AttributeKey,
+        target_node_index: AttributeKey,
+    ) -> Result<(), PyGraphsterError> {
+        self.mutate_structure(|graph| {
+            graph.node_attributes(source_node_index.clone())?;
+            graph.node_attributes(target_node_index.clone())?;
+
+            let attributes = graph.remove_edge(&edge_index)?;
+
+            graph.add_edge_with_index(edge_index, source_node_index, target_node_index, attributes)
+        })
+    }
+
+    fn set_node_attribute_values(
+        &mut self,
+        key: AttributeKey,
+        values: Vec<(AttributeKey, AttributeValue)>,
+    ) -> PyResult<()> {
+        let values = values.into_iter().collect::<HashMap<_, _>>();
+
+        values
+            .par_iter()
+            .try_for_each(|(node_index, _)| {
+                self.graph.node_attributes(node_index.clone()).map(|_| ())
+            })
+            .map_err(PyGraphsterError::from)?;
+
+        let graph = &mut self.graph;
+        let entries = values
+            .into_iter()
+            .map(|(node_index, value)| {
+                let attributes = graph
+                    .node_attributes_mut(node_index.clone())
+                    .expect("Node must exist");
+
+                (node_index, std::mem::take(attributes), value)
+            })
+            .collect();
+
+        for (node_index, attributes) in insert_attribute_values(&key, entries) {
+            *graph
+                .node_attributes_mut(node_index)
+                .expect("Node must exist") = attributes;
+        }
+
+        Ok(())
+    }
+
+    fn set_edge_attribute_values(
+        &mut self,
+        key: AttributeKey,
+        values: Vec<(EdgeIndex, AttributeValue)>,
+    ) -> PyResult<()> {
+        let values = values.into_iter().collect::<HashMap<_, _>>();
+
+        values
+            .par_iter()
+            .try_for_each(|(edge_index, _)| self.graph.edge_attributes(edge_index).map(|_| ()))
+            .map_err(PyGraphsterError::from)?;
+
+        let graph = &mut self.graph;
+        let entries = values
+            .into_iter()
+            .map(|(edge_index, value)| {
+                let attributes = graph
+                    .edge_attributes_mut(&edge_index)
+                    .expect("Edge must exist");
+
+                (edge_index, std::mem::take(attributes), value)
+            })
+            .collect();
+
+        for (edge_index, attributes) in insert_attribute_values(&key, entries) {
+            *graph
+                .edge_attributes_mut(&edge_index)
+                .expect("Edge must exist") = attributes;
+        }
+
+        Ok(())
+    }
+}
+
+fn insert_attribute_values<T: Send>(
+    key: &AttributeKey,
+    entries: Vec<(T, Attributes, AttributeValue)>,
+) -> Vec<(T, Attributes)> {
+    entries
+        .into_par_iter()
+        .map(|(index, mut attributes, value)| {
+            attributes.insert(key.clone(), value);
+
+            (index, attributes)
+        })
+        .collect()
+}
+
+fn components_to_python(
+    components: Vec<Vec<AttributeKey>>,
+    native_types: bool,
+    py: Python<'_>,
+) -> PyObject {
+    components
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(PyNodeIndex::from)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .into_py_with_mode(py, native_types)
+}
+
+impl GraphSource for PyDataGraph {
+    fn data_graph<'py>(slf: &Bound<'py, Self>) -> Bound<'py, PyDataGraph> {
+        slf.clone()
+    }
+
+    fn definition(_slf: &Bound<'_, Self>) -> ViewDefinition {
+        ViewDefinition::default()
+    }
+}
+
+impl From<DataGraph> for PyDataGraph {
+    fn from(value: DataGraph) -> Self {
+        Self {
+            graph: value,
+            native_types: false,
+            generation: 0,
+        }
+    }
+}
+
+implement_graph_pymethods!(PyDataGraph, {
     #[new]
-    fn new() -> Self {
-        Self(DataGraph::new())
+    #[allow(clippy::new_without_default)]
+    #[pyo3(signature = (native_types = false))]
+    fn new(native_types: bool) -> Self {
+        Self {
+            graph: DataGraph::new(),
+            native_types,
+            generation: 0,
+        }
     }
 
     #[staticmethod]
-    fn from_nodes(nodes: Vec<(PyNodeIndex, PyAttributes)>) -> Self {
-        Self(DataGraph::from_nodes(nodes))
+    #[pyo3(signature = (nodes, native_types = false))]
+    fn from_nodes(nodes: Vec<(PyNodeIndex, PyAttributes)>, native_types: bool) -> Self {
+        Self {
+            native_types,
+            ..DataGraph::from_nodes(nodes).into()
+        }
     }
 
     #[staticmethod]
+    #[pyo3(signature = (nodes, edges, native_types = false))]
     fn from_nodes_and_edges(
         nodes: Vec<(PyNodeIndex, PyAttributes)>,
         edges: Vec<(PyNodeIndex, PyNodeIndex, PyAttributes)>,
+        native_types: bool,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            native_types,
+            ..DataGraph::from_nodes_and_edges(nodes, edges)
+                .map_err(PyGraphsterError::from)?
+                .into()
+        })
+    }
+
+    #[cfg(feature = "polars")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        nodes_dataframe,
+        edges_dataframe,
+        node_index_column,
+        source_column,
+        target_column,
+        native_types = false
+    ))]
+    fn from_polars(
+        nodes_dataframe: PyDataFrame,
+        edges_dataframe: PyDataFrame,
+        node_index_column: &str,
+        source_column: &str,
+        target_column: &str,
+        native_types: bool,
     ) -> PyResult<Self> {
-        Ok(Self(
-            DataGraph::from_nodes_and_edges(nodes, edges).map_err(PyGraphsterError::from)?,
-        ))
+        let nodes = convert_dataframe_to_nodes(&nodes_dataframe.0, node_index_column)?;
+        let edges = convert_dataframe_to_edges(&edges_dataframe.0, source_column, target_column)?;
+
+        Ok(Self {
+            native_types,
+            ..DataGraph::from_nodes_and_edges(nodes, edges)
+                .map_err(PyGraphsterError::from)?
+                .into()
+        })
     }
 
     fn add_node(&mut self, node_index: PyNodeIndex, attributes: PyAttributes) -> PyResult<()> {
-        Ok(self
-            .0
-            .add_node(node_index, attributes)
-            .map_err(PyGraphsterError::from)?)
+        Ok(self.mutate_structure(|graph| graph.add_node(node_index, attributes))?)
     }
 
     fn add_nodes(&mut self, nodes: Vec<(PyNodeIndex, PyAttributes)>) -> PyResult<()> {
-        Ok(self.0.add_nodes(nodes).map_err(PyGraphsterError::from)?)
+        Ok(self.mutate_structure(|graph| graph.add_nodes(nodes))?)
     }
 
     fn add_edge(
@@ -54,125 +313,473 @@ impl PyDataGraph {
         source_node_index: PyNodeIndex,
         target_node_index: PyNodeIndex,
         attributes: PyAttributes,
-    ) -> PyResult<PyEdgeIndex> {
-        Ok(self
-            .0
-            .add_edge(source_node_index, target_node_index, attributes)
-            .map_err(PyGraphsterError::from)?
-            .into())
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        Ok(PyEdgeIndex::from(self.mutate_structure(|graph| {
+            graph.add_edge(source_node_index, target_node_index, attributes)
+        })?)
+        .into_py_with_mode(py, self.native_types))
     }
 
     fn add_edges(
         &mut self,
         edges: Vec<(PyNodeIndex, PyNodeIndex, PyAttributes)>,
-    ) -> PyResult<Vec<PyEdgeIndex>> {
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
         Ok(self
-            .0
-            .add_edges(edges)
-            .map_err(PyGraphsterError::from)?
+            .mutate_structure(|graph| graph.add_edges(edges))?
             .into_par_iter()
-            .map(|edge_index| edge_index.into())
-            .collect())
+            .map(PyEdgeIndex::from)
+            .collect::<Vec<_>>()
+            .into_py_with_mode(py, self.native_types))
     }
 
-    fn remove_node(&mut self, node_index: PyNodeIndex) -> PyResult<PyAttributes> {
+    fn remove_node(&mut self, node_index: PyNodeIndex, py: Python<'_>) -> PyResult<PyObject> {
         Ok(self
-            .0
-            .remove_node(node_index)
-            .map_err(PyGraphsterError::from)?
+            .mutate_structure(|graph| graph.remove_node(node_index))?
             .into_par_iter()
             .map(|(key, value)| (key.into(), value.into()))
-            .collect())
+            .collect::<PyAttributes>()
+            .into_py_with_mode(py, self.native_types))
     }
 
-    fn remove_edge(&mut self, edge_index: PyEdgeIndex) -> PyResult<PyAttributes> {
+    fn remove_edge(&mut self, edge_index: PyEdgeIndex, py: Python<'_>) -> PyResult<PyObject> {
         Ok(self
-            .0
-            .remove_edge(&edge_index.0)
-            .map_err(PyGraphsterError::from)?
+            .mutate_structure(|graph| graph.remove_edge(&edge_index.0))?
             .into_par_iter()
             .map(|(key, value)| (key.into(), value.into()))
-            .collect())
+            .collect::<PyAttributes>()
+            .into_py_with_mode(py, self.native_types))
     }
 
-    fn node_count(&self) -> usize {
-        self.0.node_count()
+    fn remove_nodes(
+        &mut self,
+        node_indices: &Bound<'_, PyAny>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let node_indices = extract_iterable::<PyNodeIndex>(node_indices)?
+            .into_iter()
+            .map(AttributeKey::from)
+            .collect::<HashSet<_>>();
+
+        node_indices
+            .par_iter()
+            .try_for_each(|node_index| self.graph.node_attributes(node_index.clone()).map(|_| ()))
+            .map_err(PyGraphsterError::from)?;
+
+        let removed = self.mutate_structure(|graph| {
+            node_indices
+                .into_iter()
+                .map(|node_index| {
+                    let attributes = graph.remove_node(node_index.clone())?;
+
+                    Ok((node_index, attributes))
+                })
+                .collect::<Result<Vec<_>, GraphsterError>>()
+        })?;
+
+        Ok(removed
+            .into_iter()
+            .map(|(node_index, attributes)| {
+                let attributes = attributes
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into()))
+                    .collect::<PyAttributes>();
+
+                (PyNodeIndex::from(node_index), attributes)
+            })
+            .collect::<HashMap<_, _>>()
+            .into_py_with_mode(py, self.native_types))
     }
 
-    fn edge_count(&self) -> usize {
-        self.0.edge_count()
+    fn remove_edges(
+        &mut self,
+        edge_indices: &Bound<'_, PyAny>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let edge_indices = extract_iterable::<PyEdgeIndex>(edge_indices)?
+            .into_iter()
+            .map(EdgeIndex::from)
+            .collect::<HashSet<_>>();
+
+        edge_indices
+            .par_iter()
+            .try_for_each(|edge_index| self.graph.edge_attributes(edge_index).map(|_| ()))
+            .map_err(PyGraphsterError::from)?;
+
+        let removed = self.mutate_structure(|graph| {
+            edge_indices
+                .into_iter()
+                .map(|edge_index| {
+                    let attributes = graph.remove_edge(&edge_index)?;
+
+                    Ok((edge_index, attributes))
+                })
+                .collect::<Result<Vec<_>, GraphsterError>>()
+        })?;
+
+        Ok(removed
+            .into_iter()
+            .map(|(edge_index, attributes)| {
+                let attributes = attributes
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into()))
+                    .collect::<PyAttributes>();
+
+                (PyEdgeIndex::from(edge_index), attributes)
+            })
+            .collect::<HashMap<_, _>>()
+            .into_py_with_mode(py, self.native_types))
     }
 
-    fn node_indices(&self) -> Vec<PyNodeIndex> {
-        self.0
-            .node_indices()
-            .map(|index| index.clone().0.into())
-            .collect()
+    fn rewire_edge(
+        &mut self,
+        edge_index: PyEdgeIndex,
+        source_node_index: PyNodeIndex,
+        target_node_index: PyNodeIndex,
+    ) -> PyResult<()> {
+        Ok(self.rewire(
+            edge_index.0,
+            source_node_index.into(),
+            target_node_index.into(),
+        )?)
     }
 
-    fn edge_indices(&self) -> Vec<PyEdgeIndex> {
-        self.0
-            .edge_indices()
-            .map(|index| (*index).into())
-            .collect()
+    fn reverse_edge(&mut self, edge_index: PyEdgeIndex) -> PyResult<()> {
+        let (source_node_index, target_node_index) = self
+            .graph
+            .edge_endpoints(&edge_index.0)
+            .map_err(PyGraphsterError::from)?;
+        let (source_node_index, target_node_index) =
+            (source_node_index.0.clone(), target_node_index.0.clone());
+
+        Ok(self.rewire(edge_index.0, target_node_index, source_node_index)?)
     }
 
-    fn contains_node(&self, node_index: PyNodeIndex) -> bool {
-        self.0.contains_node(node_index)
+    fn set_node_attribute(
+        &mut self,
+        node_index: PyNodeIndex,
+        key: PyAttributeKey,
+        value: PyAttributeValue,
+    ) -> PyResult<()> {
+        self.graph
+            .node_attributes_mut(node_index)
+            .map_err(PyGraphsterError::from)?
+            .insert(key.into(), value.into());
+
+        Ok(())
     }
 
-    fn contains_edge(&self, edge_index: PyEdgeIndex) -> bool {
-        self.0.contains_edge(&edge_index.0)
+    fn update_node_attributes(
+        &mut self,
+        node_index: PyNodeIndex,
+        attributes: PyAttributes,
+    ) -> PyResult<()> {
+        self.graph
+            .node_attributes_mut(node_index)
+            .map_err(PyGraphsterError::from)?
+            .extend(
+                attributes
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into())),
+            );
+
+        Ok(())
     }
 
-    fn node_attributes(&self, node_index: PyNodeIndex) -> PyResult<PyAttributes> {
-        Ok(self
-            .0
-            .node_attributes(node_index)
+    fn delete_node_attribute(
+        &mut self,
+        node_index: PyNodeIndex,
+        key: PyAttributeKey,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let node_index = AttributeKey::from(node_index);
+        let key = AttributeKey::from(key);
+
+        let value = self
+            .graph
+            .node_attributes_mut(node_index.clone())
             .map_err(PyGraphsterError::from)?
-            .into_par_iter()
-            .map(|(key, value)| (key.clone().into(), value.clone().into()))
-            .collect())
+            .remove(&key)
+            .ok_or_else(|| {
+                PyGraphsterError::AttributeNotFound(format!(
+                    "Attribute {} not found on node {}",
+                    key, node_index
+                ))
+            })?;
+
+        Ok(PyAttributeValue::from(value).into_py_with_mode(py, self.native_types))
     }
 
-    fn edge_attributes(&self, edge_index: PyEdgeIndex) -> PyResult<PyAttributes> {
-        Ok(self
-            .0
-            .edge_attributes(&edge_index.0)
+    fn set_edge_attribute(
+        &mut self,
+        edge_index: PyEdgeIndex,
+        key: PyAttributeKey,
+        value: PyAttributeValue,
+    ) -> PyResult<()> {
+        self.graph
+            .edge_attributes_mut(&edge_index.0)
+            .map_err(PyGraphsterError::from)?
+            .insert(key.into(), value.into());
+
+        Ok(())
+    }
+
+    fn update_edge_attributes(
+        &mut self,
+        edge_index: PyEdgeIndex,
+        attributes: PyAttributes,
+    ) -> PyResult<()> {
+        self.graph
+            .edge_attributes_mut(&edge_index.0)
+            .map_err(PyGraphsterError::from)?
+            .extend(
+                attributes
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into())),
+            );
+
+        Ok(())
+    }
+
+    fn delete_edge_attribute(
+        &mut self,
+        edge_index: PyEdgeIndex,
+        key: PyAttributeKey,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let key = AttributeKey::from(key);
+
+        let value = self
+            .graph
+            .edge_attributes_mut(&edge_index.0)
             .map_err(PyGraphsterError::from)?
+            .remove(&key)
+            .ok_or_else(|| {
+                PyGraphsterError::AttributeNotFound(format!(
+                    "Attribute {} not found on edge {}",
+                    key,
+                    usize::from(edge_index.0)
+                ))
+            })?;
+
+        Ok(PyAttributeValue::from(value).into_py_with_mode(py, self.native_types))
+    }
+
+    fn set_node_attribute_many(
+        &mut self,
+        key: PyAttributeKey,
+        values: HashMap<PyNodeIndex, PyAttributeValue>,
+    ) -> PyResult<()> {
+        let values = values
             .into_par_iter()
-            .map(|(key, value)| (key.clone().into(), value.clone().into()))
-            .collect())
+            .map(|(node_index, value)| (node_index.into(), value.into()))
+            .collect();
+
+        self.set_node_attribute_values(key.into(), values)
     }
 
-    fn incoming_edge_indices(&self, node_index: PyNodeIndex) -> PyResult<Vec<PyEdgeIndex>> {
-        Ok(self
-            .0
+    fn set_edge_attribute_many(
+        &mut self,
+        key: PyAttributeKey,
+        values: HashMap<PyEdgeIndex, PyAttributeValue>,
+    ) -> PyResult<()> {
+        let values = values
+            .into_par_iter()
+            .map(|(edge_index, value)| (edge_index.into(), value.into()))
+            .collect();
+
+        self.set_edge_attribute_values(key.into(), values)
+    }
+
+    #[cfg(feature = "polars")]
+    fn set_node_attribute_from_polars(
+        &mut self,
+        key: PyAttributeKey,
+        dataframe: PyDataFrame,
+        node_index_column: &str,
+        value_column: &str,
+    ) -> PyResult<()> {
+        let values =
+            convert_dataframe_to_node_values(&dataframe.0, node_index_column, value_column)?;
+
+        self.set_node_attribute_values(key.into(), values)
+    }
+
+    #[cfg(feature = "polars")]
+    fn set_edge_attribute_from_polars(
+        &mut self,
+        key: PyAttributeKey,
+        dataframe: PyDataFrame,
+        edge_index_column: &str,
+        value_column: &str,
+    ) -> PyResult<()> {
+        let values =
+            convert_dataframe_to_edge_values(&dataframe.0, edge_index_column, value_column)?;
+
+        self.set_edge_attribute_values(key.into(), values)
+    }
+
+    #[getter]
+    fn nodes(slf: PyRef<'_, Self>) -> PyNodesView {
+        PyNodesView::from(Py::from(slf))
+    }
+
+    #[getter]
+    fn edges(slf: PyRef<'_, Self>) -> PyEdgesView {
+        PyEdgesView::from(Py::from(slf))
+    }
+
+    #[pyo3(signature = (node_indices, preserve_edge_indices = false))]
+    fn subgraph(
+        &self,
+        node_indices: &Bound<'_, PyAny>,
+        preserve_edge_indices: bool,
+    ) -> PyResult<Self> {
+        let node_indices = extract_iterable::<PyNodeIndex>(node_indices)?
+            .into_iter()
+            .map(AttributeKey::from)
+            .collect();
+
+        Ok(Self {
+            native_types: self.native_types,
+            ..subgraph(&self.graph, node_indices, preserve_edge_indices)
+                .map_err(PyGraphsterError::from)?
+                .into()
+        })
+    }
+
+    #[pyo3(signature = (edge_indices, preserve_edge_indices = false))]
+    fn edge_subgraph(
+        &self,
+        edge_indices: &Bound<'_, PyAny>,
+        preserve_edge_indices: bool,
+    ) -> PyResult<Self> {
+        let edge_indices = extract_iterable::<PyEdgeIndex>(edge_indices)?
+            .into_iter()
+            .map(EdgeIndex::from)
+            .collect();
+
+        Ok(Self {
+            native_types: self.native_types,
+            ..edge_subgraph(&self.graph, edge_indices, preserve_edge_indices)
+                .map_err(PyGraphsterError::from)?
+                .into()
+        })
+    }
+
+    #[cfg(feature = "polars")]
+    #[pyo3(signature = (node_index_column = "node_index"))]
+    fn nodes_to_polars(&self, node_index_column: &str) -> PyResult<PyDataFrame> {
+        Ok(PyDataFrame(convert_nodes_to_dataframe(
+            &self.graph,
+            node_index_column,
+        )?))
+    }
+
+    #[cfg(feature = "polars")]
+    #[pyo3(signature = (
+        edge_index_column = "edge_index",
+        source_column = "source_node_index",
+        target_column = "target_node_index"
+    ))]
+    fn edges_to_polars(
+        &self,
+        edge_index_column: &str,
+        source_column: &str,
+        target_column: &str,
+    ) -> PyResult<PyDataFrame> {
+        Ok(PyDataFrame(convert_edges_to_dataframe(
+            &self.graph,
+            edge_index_column,
+            source_column,
+            target_column,
+        )?))
+    }
+
+    #[cfg(feature = "polars")]
+    #[pyo3(signature = (
+        alpha = 0.85,
+        weight = None,
+        personalization = None,
+        tol = 1e-6,
+        max_iter = 100,
+        node_index_column = "node_index",
+        score_column = "pagerank"
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn pagerank_to_polars(
+        &self,
+        alpha: f64,
+        weight: Option<PyAttributeKey>,
+        personalization: Option<PyPersonalization>,
+        tol: f64,
+        max_iter: usize,
+        node_index_column: &str,
+        score_column: &str,
+        py: Python<'_>,
+    ) -> PyResult<PyDataFrame> {
+        let weight = weight.map(AttributeKey::from);
+        let personalization = personalization.map(Personalization::from);
+        let view = GraphView::from(&self.graph);
+        let scores = py.allow_threads(|| {
+            pagerank(
+                &view,
+                alpha,
+                weight.as_ref(),
+                personalization.as_ref(),
+                tol,
+                max_iter,
+            )
+        })?;
+
+        Ok(PyDataFrame(convert_node_values_to_dataframe(
+            scores,
+            node_index_column,
+            score_column,
+        )?))
+    }
+
+    fn incoming_edge_indices(
+        slf: PyRef<'_, Self>,
+        node_index: PyNodeIndex,
+    ) -> PyResult<PyEdgeIndexIterator> {
+        let edge_indices = slf
+            .graph
             .incoming_edge_indices(node_index)
             .map_err(PyGraphsterError::from)?
-            .map(|index| (*index).into())
-            .collect())
+            .copied()
+            .collect();
+
+        Ok(PyEdgeIndexIterator::tracked(slf, edge_indices))
     }
 
-    fn outgoing_edge_indices(&self, node_index: PyNodeIndex) -> PyResult<Vec<PyEdgeIndex>> {
-        Ok(self
-            .0
+    fn outgoing_edge_indices(
+        slf: PyRef<'_, Self>,
+        node_index: PyNodeIndex,
+    ) -> PyResult<PyEdgeIndexIterator> {
+        let edge_indices = slf
+            .graph
             .outgoing_edge_indices(node_index)
             .map_err(PyGraphsterError::from)?
-            .map(|index| (*index).into())
-            .collect())
+            .copied()
+            .collect();
+
+        Ok(PyEdgeIndexIterator::tracked(slf, edge_indices))
     }
 
     fn edges_connecting(
-        &self,
+        slf: PyRef<'_, Self>,
         source_node_index: PyNodeIndex,
         target_node_index: PyNodeIndex,
-    ) -> PyResult<Vec<PyEdgeIndex>> {
-        Ok(self
-            .0
+    ) -> PyResult<PyEdgeIndexIterator> {
+        let edge_indices = slf
+            .graph
             .edges_connecting(source_node_index, target_node_index)
             .map_err(PyGraphsterError::from)?
-            .map(|index| (*index).into())
-            .collect())
+            .copied()
+            .collect();
+
+        Ok(PyEdgeIndexIterator::tracked(slf, edge_indices))
     }
-}
+});
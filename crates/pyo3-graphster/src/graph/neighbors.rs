@@ -0,0 +1,85 @@
+use super::topology::GraphView;
+use crate::errors::PyGraphsterError;
+use graphster::{datatypes::AttributeKey, errors::GraphsterError};
+use pyo3::{types::PyAnyMethods, Bound, FromPyObject, PyAny, PyResult};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Outgoing,
+    Incoming,
+    Both,
+}
+
+impl<'a> FromPyObject<'a> for Direction {
+    fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
+        match ob.extract::<String>().as_deref() {
+            Ok("out") => Ok(Self::Outgoing),
+            Ok("in") => Ok(Self::Incoming),
+            Ok("both") => Ok(Self::Both),
+            _ => Err(
+                PyGraphsterError::from(GraphsterError::ConversionError(format!(
+                    "Could not convert {} to Direction: value must be one of 'out', 'in' or 'both'",
+                    ob
+                )))
+                .into(),
+            ),
+        }
+    }
+}
+
+pub(crate) fn neighbors(
+    view: &GraphView<'_>,
+    node_index: &AttributeKey,
+    direction: Direction,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    let mut edges = Vec::new();
+
+    if direction != Direction::Incoming {
+        edges.extend(view.outgoing_edges(node_index)?);
+    }
+
+    if direction == Direction::Incoming || (direction == Direction::Both && !view.is_undirected()) {
+        edges.extend(view.incoming_edges(node_index)?);
+    }
+
+    let mut seen = HashSet::new();
+
+    Ok(edges
+        .into_iter()
+        .filter(|(_, neighbor_index)| seen.insert(*neighbor_index))
+        .map(|(_, neighbor_index)| neighbor_index.clone())
+        .collect())
+}
+
+pub(crate) fn degree(
+    view: &GraphView<'_>,
+    node_index: &AttributeKey,
+    direction: Direction,
+) -> Result<usize, PyGraphsterError> {
+    Ok(match direction {
+        Direction::Outgoing => view.outgoing_edges(node_index)?.len(),
+        Direction::Incoming => view.incoming_edges(node_index)?.len(),
+        Direction::Both if view.is_undirected() => view.outgoing_edges(node_index)?.len(),
+        Direction::Both => {
+            view.outgoing_edges(node_index)?.len() + view.incoming_edges(node_index)?.len()
+        }
+    })
+}
+
+pub(crate) fn degrees(
+    view: &GraphView<'_>,
+    node_indices: Option<Vec<AttributeKey>>,
+    direction: Direction,
+) -> Result<Vec<(AttributeKey, usize)>, PyGraphsterError> {
+    node_indices
+        .unwrap_or_else(|| view.node_indices())
+        .into_par_iter()
+        .map(|node_index| {
+            let node_degree = degree(view, &node_index, direction)?;
+
+            Ok((node_index, node_degree))
+        })
+        .collect()
+}
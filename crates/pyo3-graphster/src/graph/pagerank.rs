@@ -0,0 +1,317 @@
+use super::{adjacency::Adjacency, topology::GraphView, PyNodeIndex};
+use crate::{
+    datatypes::key::PyAttributeKey, errors::PyGraphsterError,
+    query::predicate::convert_attributevalue_to_f64,
+};
+use graphster::{datatypes::AttributeKey, errors::GraphsterError};
+use pyo3::FromPyObject;
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Personalization {
+    Nodes(HashMap<AttributeKey, f64>),
+    Attribute(AttributeKey),
+}
+
+#[derive(FromPyObject)]
+pub(crate) enum PyPersonalization {
+    Nodes(HashMap<PyNodeIndex, f64>),
+    Attribute(PyAttributeKey),
+}
+
+impl From<PyPersonalization> for Personalization {
+    fn from(value: PyPersonalization) -> Self {
+        match value {
+            PyPersonalization::Nodes(values) => Self::Nodes(
+                values
+                    .into_iter()
+                    .map(|(node_index, value)| (node_index.into(), value))
+                    .collect(),
+            ),
+            PyPersonalization::Attribute(key) => Self::Attribute(key.into()),
+        }
+    }
+}
+
+fn personalization_error(message: String) -> PyGraphsterError {
+    GraphsterError::ConversionError(message).into()
+}
+
+fn checked_personalization_value(
+    node_index: &AttributeKey,
+    value: f64,
+) -> Result<f64, PyGraphsterError> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(personalization_error(format!(
+            "Personalization value of node {} must be a non-negative number, got {}",
+            node_index, value
+        )));
+    }
+
+    Ok(value)
+}
+
+fn personalization_vector(
+    view: &GraphView<'_>,
+    adjacency: &Adjacency,
+    personalization: Option<&Personalization>,
+) -> Result<Vec<f64>, PyGraphsterError> {
+    let values = match personalization {
+        None => vec![1.0; adjacency.node_count()],
+        Some(Personalization::Nodes(values)) => {
+            let mut vector = vec![0.0; adjacency.node_count()];
+
+            for (node_index, value) in values {
+                vector[adjacency.position(node_index)?] =
+                    checked_personalization_value(node_index, *value)?;
+            }
+
+            vector
+        }
+        Some(Personalization::Attribute(key)) => adjacency
+            .node_indices
+            .par_iter()
+            .map(|node_index| {
+                let Some(value) = view.node_attributes(node_index)?.get(key) else {
+                    return Ok(0.0);
+                };
+
+                let value = convert_attributevalue_to_f64(value).ok_or_else(|| {
+                    personalization_error(format!(
+                        "Could not convert personalization attribute {} of node {} to a number: {:?}",
+                        key, node_index, value
+                    ))
+                })?;
+
+                checked_personalization_value(node_index, value)
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+
+    let total = values.iter().sum::<f64>();
+
+    if total <= 0.0 {
+        return Err(personalization_error(
+            "Personalization values must sum to a positive number".to_string(),
+        ));
+    }
+
+    Ok(values.into_iter().map(|value| value / total).collect())
+}
+
+pub(crate) fn pagerank(
+    view: &GraphView<'_>,
+    alpha: f64,
+    weight: Option<&AttributeKey>,
+    personalization: Option<&Personalization>,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, weight)?;
+    let node_count = adjacency.node_count();
+
+    if node_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    if adjacency.has_negative_weights() {
+        return Err(PyGraphsterError::NegativeWeight(
+            "PageRank requires non-negative edge weights".to_string(),
+        ));
+    }
+
+    let personalization = personalization_vector(view, &adjacency, personalization)?;
+    let out_weights = adjacency
+        .outgoing
+        .par_iter()
+        .map(|edges| edges.iter().map(|(_, weight)| weight).sum::<f64>())
+        .collect::<Vec<_>>();
+
+    let mut incoming = vec![Vec::new(); node_count];
+
+    for (position, edges) in adjacency.outgoing.iter().enumerate() {
+        if out_weights[position] == 0.0 {
+            continue;
+        }
+
+        for &(neighbor, weight) in edges {
+            incoming[neighbor].push((position, weight / out_weights[position]));
+        }
+    }
+
+    let mut scores = vec![1.0 / node_count as f64; node_count];
+
+    for _ in 0..max_iter {
+        let dangling = (0..node_count)
+            .into_par_iter()
+            .filter(|position| out_weights[*position] == 0.0)
+            .map(|position| scores[position])
+            .sum::<f64>();
+        let teleport = alpha * dangling + 1.0 - alpha;
+
+        let next = incoming
+            .par_iter()
+            .zip(personalization.par_iter())
+            .map(|(edges, personalization)| {
+                alpha
+                    * edges
+                        .iter()
+                        .map(|&(source, weight)| scores[source] * weight)
+                        .sum::<f64>()
+                    + teleport * personalization
+            })
+            .collect::<Vec<_>>();
+
+        let error = next
+            .par_iter()
+            .zip(scores.par_iter())
+            .map(|(next, previous)| (next - previous).abs())
+            .sum::<f64>();
+
+        scores = next;
+
+        if error < node_count as f64 * tol {
+            return Ok(adjacency.node_indices.into_iter().zip(scores).collect());
+        }
+    }
+
+    Err(PyGraphsterError::Convergence(format!(
+        "PageRank failed to converge within {} iterations",
+        max_iter
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::tests::{assert_values, graph, node, weight};
+    use graphster::{datatypes::AttributeValue, graph::DataGraph};
+
+    const EDGES: [(usize, usize, f64); 5] = [
+        (0, 1, 1.0),
+        (1, 2, 2.0),
+        (2, 0, 1.0),
+        (2, 3, 3.0),
+        (3, 1, 1.0),
+    ];
+
+    const PERSONALIZED: [(usize, f64); 4] = [
+        (0, 0.15686392048050798),
+        (1, 0.33041721537533564),
+        (2, 0.28085494366364844),
+        (3, 0.231863920480508),
+    ];
+
+    fn bias() -> AttributeKey {
+        AttributeKey::String("bias".to_string())
+    }
+
+    fn dangling_graph() -> DataGraph {
+        let mut edges = EDGES.to_vec();
+        edges.push((1, 4, 1.0));
+
+        graph(&edges)
+    }
+
+    #[test]
+    fn pagerank_matches_networkx() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        assert_values(
+            pagerank(&view, 0.85, None, None, 1e-6, 100).unwrap(),
+            &[
+                (0, 0.17359061775974502),
+                (1, 0.33260554622228633),
+                (2, 0.3202132182582236),
+                (3, 0.17359061775974502),
+            ],
+        );
+        assert_values(
+            pagerank(&view, 0.85, Some(&weight()), None, 1e-6, 100).unwrap(),
+            &[
+                (0, 0.10554530887987251),
+                (1, 0.3326055462222864),
+                (2, 0.3202132182582236),
+                (3, 0.24163592663961753),
+            ],
+        );
+    }
+
+    #[test]
+    fn personalized_pagerank_matches_networkx() {
+        let mut graph = graph(&EDGES);
+
+        for (node_index, value) in [(0, 1.0), (3, 3.0)] {
+            graph
+                .node_attributes_mut(node(node_index))
+                .unwrap()
+                .insert(bias(), AttributeValue::Float64(value));
+        }
+
+        let view = GraphView::from(&graph);
+        let nodes = Personalization::Nodes(HashMap::from([(node(0), 1.0), (node(3), 3.0)]));
+
+        assert_values(
+            pagerank(&view, 0.85, None, Some(&nodes), 1e-6, 100).unwrap(),
+            &PERSONALIZED,
+        );
+        assert_values(
+            pagerank(
+                &view,
+                0.85,
+                None,
+                Some(&Personalization::Attribute(bias())),
+                1e-6,
+                100,
+            )
+            .unwrap(),
+            &PERSONALIZED,
+        );
+    }
+
+    #[test]
+    fn dangling_nodes_follow_the_personalization() {
+        let graph = dangling_graph();
+        let view = GraphView::from(&graph);
+
+        assert_values(
+            pagerank(&view, 0.85, None, None, 1e-6, 100).unwrap(),
+            &[
+                (0, 0.14691715362363034),
+                (1, 0.3131654604079208),
+                (2, 0.19650011617240926),
+                (3, 0.14691715362363034),
+                (4, 0.19650011617240926),
+            ],
+        );
+
+        let personalization =
+            Personalization::Nodes(HashMap::from([(node(0), 1.0), (node(4), 1.0)]));
+
+        assert_values(
+            pagerank(&view, 0.85, None, Some(&personalization), 1e-6, 100).unwrap(),
+            &[
+                (0, 0.25922027722998703),
+                (1, 0.2603013222300806),
+                (2, 0.11062906165497269),
+                (3, 0.047016906854931255),
+                (4, 0.3228324320300284),
+            ],
+        );
+    }
+
+    #[test]
+    fn iteration_limit_raises_convergence_error() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        assert!(matches!(
+            pagerank(&view, 0.85, None, None, 1e-6, 2),
+            Err(PyGraphsterError::Convergence(_))
+        ));
+    }
+}
@@ -0,0 +1,421 @@
+use super::{
+    adjacency::{Adjacency, LazyAdjacency},
+    topology::GraphView,
+};
+use crate::errors::PyGraphsterError;
+use graphster::datatypes::AttributeKey;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct HeapEntry {
+    pub(crate) distance: f64,
+    pub(crate) position: usize,
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ShortestPathTree {
+    pub(crate) distances: Vec<Option<f64>>,
+    pub(crate) predecessors: Vec<Option<usize>>,
+}
+
+impl ShortestPathTree {
+    pub(crate) fn new(node_count: usize, source: usize) -> Self {
+        let mut distances = vec![None; node_count];
+        distances[source] = Some(0.0);
+
+        Self {
+            distances,
+            predecessors: vec![None; node_count],
+        }
+    }
+
+    pub(crate) fn grow(&mut self, node_count: usize) {
+        self.distances.resize(node_count, None);
+        self.predecessors.resize(node_count, None);
+    }
+
+    pub(crate) fn path(&self, target: usize) -> Option<Vec<usize>> {
+        self.distances[target]?;
+
+        let mut path = vec![target];
+
+        while let Some(predecessor) = self.predecessors[*path.last().expect("Path is not empty")] {
+            path.push(predecessor);
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+fn negative_cycle_error() -> PyGraphsterError {
+    PyGraphsterError::NegativeCycle("Graph contains a negative cycle".to_string())
+}
+
+pub(crate) fn dijkstra(
+    adjacency: &Adjacency,
+    source: usize,
+    target: Option<usize>,
+    potentials: Option<&[f64]>,
+) -> ShortestPathTree {
+    let reduced_weight = |from: usize, to: usize, weight: f64| match potentials {
+        Some(potentials) => weight + potentials[from] - potentials[to],
+        None => weight,
+    };
+
+    let mut tree = ShortestPathTree::new(adjacency.node_count(), source);
+    let mut settled = vec![false; adjacency.node_count()];
+    let mut heap = BinaryHeap::from([HeapEntry {
+        distance: 0.0,
+        position: source,
+    }]);
+
+    while let Some(HeapEntry { distance, position }) = heap.pop() {
+        if settled[position] {
+            continue;
+        }
+
+        settled[position] = true;
+
+        if target == Some(position) {
+            break;
+        }
+
+        for &(neighbor, weight) in &adjacency.outgoing[position] {
+            let candidate = distance + reduced_weight(position, neighbor, weight).max(0.0);
+
+            if tree.distances[neighbor].is_none_or(|current| candidate < current) {
+                tree.distances[neighbor] = Some(candidate);
+                tree.predecessors[neighbor] = Some(position);
+                heap.push(HeapEntry {
+                    distance: candidate,
+                    position: neighbor,
+                });
+            }
+        }
+    }
+
+    if let Some(potentials) = potentials {
+        for (position, distance) in tree.distances.iter_mut().enumerate() {
+            if let Some(distance) = distance {
+                *distance += potentials[position] - potentials[source];
+            }
+        }
+    }
+
+    tree
+}
+
+fn lazy_dijkstra(
+    adjacency: &mut LazyAdjacency<'_>,
+    source: usize,
+    target: Option<usize>,
+) -> Result<ShortestPathTree, PyGraphsterError> {
+    let mut tree = ShortestPathTree::new(adjacency.node_count(), source);
+    let mut settled = vec![false; adjacency.node_count()];
+    let mut heap = BinaryHeap::from([HeapEntry {
+        distance: 0.0,
+        position: source,
+    }]);
+
+    while let Some(HeapEntry { distance, position }) = heap.pop() {
+        if settled[position] {
+            continue;
+        }
+
+        settled[position] = true;
+
+        if target == Some(position) {
+            break;
+        }
+
+        adjacency.outgoing(position)?;
+        tree.grow(adjacency.node_count());
+        settled.resize(adjacency.node_count(), false);
+
+        for &(neighbor, weight) in adjacency.expanded(position) {
+            let candidate = distance + weight;
+
+            if tree.distances[neighbor].is_none_or(|current| candidate < current) {
+                tree.distances[neighbor] = Some(candidate);
+                tree.predecessors[neighbor] = Some(position);
+                heap.push(HeapEntry {
+                    distance: candidate,
+                    position: neighbor,
+                });
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+fn relax<'e>(
+    node_count: usize,
+    outgoing: impl Fn(usize) -> &'e [(usize, f64)],
+    tree: &mut ShortestPathTree,
+) -> bool {
+    let mut changed = false;
+
+    for position in 0..node_count {
+        let Some(distance) = tree.distances[position] else {
+            continue;
+        };
+
+        for &(neighbor, weight) in outgoing(position) {
+            let candidate = distance + weight;
+
+            if tree.distances[neighbor].is_none_or(|current| candidate < current) {
+                tree.distances[neighbor] = Some(candidate);
+                tree.predecessors[neighbor] = Some(position);
+                changed = true;
+            }
+        }
+    }
+
+    changed
+}
+
+fn bellman_ford<'e>(
+    node_count: usize,
+    outgoing: impl Fn(usize) -> &'e [(usize, f64)],
+    mut tree: ShortestPathTree,
+) -> Result<ShortestPathTree, PyGraphsterError> {
+    for _ in 0..node_count {
+        if !relax(node_count, &outgoing, &mut tree) {
+            return Ok(tree);
+        }
+    }
+
+    Err(negative_cycle_error())
+}
+
+fn single_source(
+    adjacency: &mut LazyAdjacency<'_>,
+    source: usize,
+    target: Option<usize>,
+) -> Result<ShortestPathTree, PyGraphsterError> {
+    if adjacency.reaches_negative_weight(source)? {
+        let adjacency = &*adjacency;
+
+        bellman_ford(
+            adjacency.node_count(),
+            |position| adjacency.expanded(position),
+            ShortestPathTree::new(adjacency.node_count(), source),
+        )
+    } else {
+        lazy_dijkstra(adjacency, source, target)
+    }
+}
+
+fn lengths(node_indices: &[AttributeKey], tree: ShortestPathTree) -> Vec<(AttributeKey, f64)> {
+    tree.distances
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, distance)| Some((node_indices[position].clone(), distance?)))
+        .collect()
+}
+
+pub(crate) fn shortest_path(
+    view: &GraphView<'_>,
+    source: &AttributeKey,
+    target: &AttributeKey,
+    weight: Option<&AttributeKey>,
+) -> Result<Option<Vec<AttributeKey>>, PyGraphsterError> {
+    let mut adjacency = LazyAdjacency::new(view, weight);
+    let source = adjacency.position(source)?;
+    let target = adjacency.position(target)?;
+
+    Ok(single_source(&mut adjacency, source, Some(target))?
+        .path(target)
+        .map(|path| {
+            path.into_iter()
+                .map(|position| adjacency.node_indices[position].clone())
+                .collect()
+        }))
+}
+
+pub(crate) fn shortest_path_lengths(
+    view: &GraphView<'_>,
+    source: &AttributeKey,
+    weight: Option<&AttributeKey>,
+) -> Result<Vec<(AttributeKey, f64)>, PyGraphsterError> {
+    let mut adjacency = LazyAdjacency::new(view, weight);
+    let source = adjacency.position(source)?;
+    let tree = single_source(&mut adjacency, source, None)?;
+
+    Ok(lengths(&adjacency.node_indices, tree))
+}
+
+pub(crate) fn all_pairs_shortest_path_lengths(
+    view: &GraphView<'_>,
+    weight: Option<&AttributeKey>,
+) -> Result<HashMap<AttributeKey, Vec<(AttributeKey, f64)>>, PyGraphsterError> {
+    let adjacency = Adjacency::new(view, weight)?;
+
+    let potentials = if adjacency.has_negative_weights() {
+        let tree = bellman_ford(
+            adjacency.node_count(),
+            |position| adjacency.outgoing[position].as_slice(),
+            ShortestPathTree {
+                distances: vec![Some(0.0); adjacency.node_count()],
+                predecessors: vec![None; adjacency.node_count()],
+            },
+        )?;
+
+        Some(tree.distances.into_iter().flatten().collect::<Vec<_>>())
+    } else {
+        None
+    };
+
+    Ok((0..adjacency.node_count())
+        .into_par_iter()
+        .map(|source| {
+            let tree = dijkstra(&adjacency, source, None, potentials.as_deref());
+
+            (
+                adjacency.node_indices[source].clone(),
+                lengths(&adjacency.node_indices, tree),
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topology::{
+        tests::{assert_values, graph, index, node, weight},
+        ViewDefinition,
+    };
+    use graphster::datatypes::AttributeValue;
+
+    const EDGES: [(usize, usize, f64); 6] = [
+        (0, 1, 4.0),
+        (0, 2, 1.0),
+        (2, 1, -2.0),
+        (1, 3, 1.0),
+        (3, 4, 2.0),
+        (2, 4, 5.0),
+    ];
+
+    #[test]
+    fn bellman_ford_matches_networkx() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        let lengths = shortest_path_lengths(&view, &node(0), Some(&weight())).unwrap();
+
+        assert_values(
+            lengths,
+            &[(0, 0.0), (1, -1.0), (2, 1.0), (3, 0.0), (4, 2.0)],
+        );
+    }
+
+    #[test]
+    fn johnson_matches_networkx() {
+        let graph = graph(&EDGES);
+        let view = GraphView::from(&graph);
+
+        let mut lengths = all_pairs_shortest_path_lengths(&view, Some(&weight()))
+            .unwrap()
+            .into_iter()
+            .map(|(source, lengths)| (index(&source), lengths))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lengths.len(), 5);
+        assert_values(
+            lengths.remove(&0).unwrap(),
+            &[(0, 0.0), (1, -1.0), (2, 1.0), (3, 0.0), (4, 2.0)],
+        );
+        assert_values(lengths.remove(&1).unwrap(), &[(1, 0.0), (3, 1.0), (4, 3.0)]);
+        assert_values(
+            lengths.remove(&2).unwrap(),
+            &[(2, 0.0), (1, -2.0), (3, -1.0), (4, 1.0)],
+        );
+        assert_values(lengths.remove(&3).unwrap(), &[(3, 0.0), (4, 2.0)]);
+        assert_values(lengths.remove(&4).unwrap(), &[(4, 0.0)]);
+    }
+
+    #[test]
+    fn negative_cycles_are_reported() {
+        let mut edges = EDGES.to_vec();
+        edges.push((3, 2, -1.0));
+        let graph = graph(&edges);
+        let view = GraphView::from(&graph);
+
+        assert!(matches!(
+            shortest_path_lengths(&view, &node(0), Some(&weight())),
+            Err(PyGraphsterError::NegativeCycle(_))
+        ));
+        assert!(matches!(
+            shortest_path(&view, &node(0), &node(4), Some(&weight())),
+            Err(PyGraphsterError::NegativeCycle(_))
+        ));
+        assert!(matches!(
+            all_pairs_shortest_path_lengths(&view, Some(&weight())),
+            Err(PyGraphsterError::NegativeCycle(_))
+        ));
+
+        let lengths = shortest_path_lengths(&view, &node(4), Some(&weight())).unwrap();
+
+        assert_values(lengths, &[(4, 0.0)]);
+    }
+
+    #[test]
+    fn negative_undirected_edges_are_negative_cycles() {
+        let graph = graph(&[(0, 1, 1.0), (1, 2, -1.0)]);
+        let definition = ViewDefinition::default().undirected();
+        let view = GraphView::new(&graph, &definition);
+
+        assert!(matches!(
+            shortest_path_lengths(&view, &node(0), Some(&weight())),
+            Err(PyGraphsterError::NegativeCycle(_))
+        ));
+    }
+
+    #[test]
+    fn unreachable_weights_are_not_read() {
+        let mut graph = graph(&[(0, 1, 1.0), (1, 2, 2.0), (3, 4, -1.0)]);
+        graph
+            .add_edge(
+                node(3),
+                node(0),
+                HashMap::from([(weight(), AttributeValue::String("heavy".to_string()))]),
+            )
+            .unwrap();
+        let view = GraphView::from(&graph);
+
+        assert_eq!(
+            shortest_path(&view, &node(0), &node(2), Some(&weight())).unwrap(),
+            Some(vec![node(0), node(1), node(2)])
+        );
+        assert_values(
+            shortest_path_lengths(&view, &node(0), Some(&weight())).unwrap(),
+            &[(0, 0.0), (1, 1.0), (2, 3.0)],
+        );
+        assert!(shortest_path_lengths(&view, &node(3), Some(&weight())).is_err());
+    }
+}
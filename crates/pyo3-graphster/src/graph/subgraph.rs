@@ -0,0 +1,97 @@
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    errors::GraphsterError,
+    graph::{DataGraph, EdgeIndex},
+};
+use std::collections::{HashMap, HashSet};
+
+type Attributes = HashMap<AttributeKey, AttributeValue>;
+
+fn build_graph(
+    nodes: Vec<(AttributeKey, Attributes)>,
+    mut edges: Vec<(EdgeIndex, AttributeKey, AttributeKey, Attributes)>,
+    preserve_edge_indices: bool,
+) -> Result<DataGraph, GraphsterError> {
+    edges.sort_by_key(|(edge_index, _, _, _)| usize::from(*edge_index));
+
+    let mut graph = DataGraph::from_nodes(nodes);
+
+    for (edge_index, source_node_index, target_node_index, attributes) in edges {
+        if preserve_edge_indices {
+            graph.add_edge_with_index(
+                edge_index,
+                source_node_index,
+                target_node_index,
+                attributes,
+            )?;
+        } else {
+            graph.add_edge(source_node_index, target_node_index, attributes)?;
+        }
+    }
+
+    Ok(graph)
+}
+
+pub(crate) fn subgraph(
+    graph: &DataGraph,
+    node_indices: HashSet<AttributeKey>,
+    preserve_edge_indices: bool,
+) -> Result<DataGraph, GraphsterError> {
+    let mut nodes = Vec::with_capacity(node_indices.len());
+    let mut edges = Vec::new();
+
+    for node_index in &node_indices {
+        nodes.push((
+            node_index.clone(),
+            graph.node_attributes(node_index.clone())?.clone(),
+        ));
+
+        for edge_index in graph.outgoing_edge_indices(node_index.clone())? {
+            let (_, target_node_index) = graph.edge_endpoints(edge_index)?;
+
+            if node_indices.contains(&target_node_index.0) {
+                edges.push((
+                    *edge_index,
+                    node_index.clone(),
+                    target_node_index.0.clone(),
+                    graph.edge_attributes(edge_index)?.clone(),
+                ));
+            }
+        }
+    }
+
+    build_graph(nodes, edges, preserve_edge_indices)
+}
+
+pub(crate) fn edge_subgraph(
+    graph: &DataGraph,
+    edge_indices: HashSet<EdgeIndex>,
+    preserve_edge_indices: bool,
+) -> Result<DataGraph, GraphsterError> {
+    let mut node_indices = HashSet::new();
+    let mut edges = Vec::with_capacity(edge_indices.len());
+
+    for edge_index in edge_indices {
+        let (source_node_index, target_node_index) = graph.edge_endpoints(&edge_index)?;
+
+        node_indices.insert(source_node_index.0.clone());
+        node_indices.insert(target_node_index.0.clone());
+        edges.push((
+            edge_index,
+            source_node_index.0.clone(),
+            target_node_index.0.clone(),
+            graph.edge_attributes(&edge_index)?.clone(),
+        ));
+    }
+
+    let nodes = node_indices
+        .into_iter()
+        .map(|node_index| {
+            let attributes = graph.node_attributes(node_index.clone())?.clone();
+
+            Ok((node_index, attributes))
+        })
+        .collect::<Result<Vec<_>, GraphsterError>>()?;
+
+    build_graph(nodes, edges, preserve_edge_indices)
+}
@@ -0,0 +1,476 @@
+use crate::{errors::PyGraphsterError, query::predicate::Predicate};
+use graphster::{
+    datatypes::{AttributeKey, AttributeValue},
+    graph::{DataGraph, EdgeIndex},
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+
+type Attributes = HashMap<AttributeKey, AttributeValue>;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ViewDefinition {
+    pub(crate) reversed: bool,
+    pub(crate) undirected: bool,
+    pub(crate) node_predicate: Option<Predicate>,
+    pub(crate) edge_predicate: Option<Predicate>,
+}
+
+fn combine_predicates(left: Option<Predicate>, right: Option<Predicate>) -> Option<Predicate> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(Predicate::And(Box::new(left), Box::new(right))),
+        (left, right) => left.or(right),
+    }
+}
+
+impl ViewDefinition {
+    pub(crate) fn reversed(&self) -> Self {
+        Self {
+            reversed: !self.reversed,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn undirected(&self) -> Self {
+        Self {
+            undirected: true,
+            ..self.clone()
+        }
+    }
+
+    // View membership checks cannot fail, so type mismatches are reported here.
+    pub(crate) fn filtered(
+        &self,
+        graph: &DataGraph,
+        node_predicate: Option<Predicate>,
+        edge_predicate: Option<Predicate>,
+    ) -> Result<Self, PyGraphsterError> {
+        let view = GraphView::new(graph, self);
+
+        if let Some(node_predicate) = &node_predicate {
+            view.select_nodes(node_predicate)?;
+        }
+        if let Some(edge_predicate) = &edge_predicate {
+            view.select_edges(edge_predicate)?;
+        }
+
+        Ok(Self {
+            node_predicate: combine_predicates(self.node_predicate.clone(), node_predicate),
+            edge_predicate: combine_predicates(self.edge_predicate.clone(), edge_predicate),
+            ..self.clone()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GraphView<'a> {
+    graph: &'a DataGraph,
+    reversed: bool,
+    undirected: bool,
+    node_predicate: Option<&'a Predicate>,
+    edge_predicate: Option<&'a Predicate>,
+}
+
+impl<'a> From<&'a DataGraph> for GraphView<'a> {
+    fn from(value: &'a DataGraph) -> Self {
+        Self {
+            graph: value,
+            reversed: false,
+            undirected: false,
+            node_predicate: None,
+            edge_predicate: None,
+        }
+    }
+}
+
+pub(crate) fn node_not_found_error(node_index: &AttributeKey) -> PyGraphsterError {
+    PyGraphsterError::NodeNotFound(format!("Node {} not found in view", node_index))
+}
+
+fn edge_not_found_error(edge_index: &EdgeIndex) -> PyGraphsterError {
+    PyGraphsterError::EdgeNotFound(format!(
+        "Edge {} not found in view",
+        usize::from(*edge_index)
+    ))
+}
+
+impl<'a> GraphView<'a> {
+    pub(crate) fn new(graph: &'a DataGraph, definition: &'a ViewDefinition) -> Self {
+        Self {
+            graph,
+            reversed: definition.reversed,
+            undirected: definition.undirected,
+            node_predicate: definition.node_predicate.as_ref(),
+            edge_predicate: definition.edge_predicate.as_ref(),
+        }
+    }
+
+    pub(crate) fn is_undirected(&self) -> bool {
+        self.undirected
+    }
+
+    pub(crate) fn is_filtered(&self) -> bool {
+        self.node_predicate.is_some() || self.edge_predicate.is_some()
+    }
+
+    pub(crate) fn contains_node(&self, node_index: &AttributeKey) -> bool {
+        self.graph
+            .node_attributes(node_index.clone())
+            .is_ok_and(|attributes| {
+                self.node_predicate
+                    .is_none_or(|predicate| predicate.matches(attributes))
+            })
+    }
+
+    pub(crate) fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        let (Ok(attributes), Ok((source_node_index, target_node_index))) = (
+            self.graph.edge_attributes(edge_index),
+            self.graph.edge_endpoints(edge_index),
+        ) else {
+            return false;
+        };
+
+        self.edge_predicate
+            .is_none_or(|predicate| predicate.matches(attributes))
+            && self.contains_node(&source_node_index.0)
+            && self.contains_node(&target_node_index.0)
+    }
+
+    pub(crate) fn node_indices(&self) -> Vec<AttributeKey> {
+        let node_indices = self.graph.node_indices().collect::<Vec<_>>();
+
+        if self.node_predicate.is_none() {
+            return node_indices
+                .into_iter()
+                .map(|node_index| node_index.0.clone())
+                .collect();
+        }
+
+        node_indices
+            .into_par_iter()
+            .filter(|node_index| self.contains_node(&node_index.0))
+            .map(|node_index| node_index.0.clone())
+            .collect()
+    }
+
+    pub(crate) fn edge_indices(&self) -> Vec<EdgeIndex> {
+        let edge_indices = self.graph.edge_indices().copied().collect::<Vec<_>>();
+
+        if !self.is_filtered() {
+            return edge_indices;
+        }
+
+        edge_indices
+            .into_par_iter()
+            .filter(|edge_index| self.contains_edge(edge_index))
+            .collect()
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        if self.node_predicate.is_none() {
+            self.graph.node_count()
+        } else {
+            self.node_indices().len()
+        }
+    }
+
+    pub(crate) fn edge_count(&self) -> usize {
+        if self.is_filtered() {
+            self.edge_indices().len()
+        } else {
+            self.graph.edge_count()
+        }
+    }
+
+    pub(crate) fn node_attributes(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<&'a Attributes, PyGraphsterError> {
+        let attributes = self.graph.node_attributes(node_index.clone())?;
+
+        if self
+            .node_predicate
+            .is_some_and(|predicate| !predicate.matches(attributes))
+        {
+            return Err(node_not_found_error(node_index));
+        }
+
+        Ok(attributes)
+    }
+
+    pub(crate) fn edge_attributes(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> Result<&'a Attributes, PyGraphsterError> {
+        let attributes = self.graph.edge_attributes(edge_index)?;
+
+        if self.is_filtered() && !self.contains_edge(edge_index) {
+            return Err(edge_not_found_error(edge_index));
+        }
+
+        Ok(attributes)
+    }
+
+    pub(crate) fn edge_endpoints(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> Result<(&'a AttributeKey, &'a AttributeKey), PyGraphsterError> {
+        let (source_node_index, target_node_index) = self.graph.edge_endpoints(edge_index)?;
+
+        if self.is_filtered() && !self.contains_edge(edge_index) {
+            return Err(edge_not_found_error(edge_index));
+        }
+
+        Ok(if self.reversed && !self.undirected {
+            (&target_node_index.0, &source_node_index.0)
+        } else {
+            (&source_node_index.0, &target_node_index.0)
+        })
+    }
+
+    fn stored_outgoing_edges(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        self.graph
+            .outgoing_edge_indices(node_index.clone())?
+            .map(|edge_index| {
+                let (_, target_node_index) = self.graph.edge_endpoints(edge_index)?;
+
+                Ok((*edge_index, &target_node_index.0))
+            })
+            .collect()
+    }
+
+    fn stored_incoming_edges(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        self.graph
+            .incoming_edge_indices(node_index.clone())?
+            .map(|edge_index| {
+                let (source_node_index, _) = self.graph.edge_endpoints(edge_index)?;
+
+                Ok((*edge_index, &source_node_index.0))
+            })
+            .collect()
+    }
+
+    fn undirected_edges(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        let mut edges = self.stored_outgoing_edges(node_index)?;
+
+        edges.extend(
+            self.stored_incoming_edges(node_index)?
+                .into_iter()
+                .filter(|(_, source_node_index)| *source_node_index != node_index),
+        );
+
+        Ok(edges)
+    }
+
+    fn visible_edges(
+        &self,
+        edges: Vec<(EdgeIndex, &'a AttributeKey)>,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        if !self.is_filtered() {
+            return Ok(edges);
+        }
+
+        let mut visible_edges = Vec::with_capacity(edges.len());
+
+        for (edge_index, node_index) in edges {
+            let attributes = self.graph.edge_attributes(&edge_index)?;
+
+            if self
+                .edge_predicate
+                .is_none_or(|predicate| predicate.matches(attributes))
+                && self.contains_node(node_index)
+            {
+                visible_edges.push((edge_index, node_index));
+            }
+        }
+
+        Ok(visible_edges)
+    }
+
+    pub(crate) fn outgoing_edges(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        self.node_attributes(node_index)?;
+
+        let edges = if self.undirected {
+            self.undirected_edges(node_index)?
+        } else if self.reversed {
+            self.stored_incoming_edges(node_index)?
+        } else {
+            self.stored_outgoing_edges(node_index)?
+        };
+
+        self.visible_edges(edges)
+    }
+
+    pub(crate) fn incoming_edges(
+        &self,
+        node_index: &AttributeKey,
+    ) -> Result<Vec<(EdgeIndex, &'a AttributeKey)>, PyGraphsterError> {
+        self.node_attributes(node_index)?;
+
+        let edges = if self.undirected {
+            self.undirected_edges(node_index)?
+        } else if self.reversed {
+            self.stored_outgoing_edges(node_index)?
+        } else {
+            self.stored_incoming_edges(node_index)?
+        };
+
+        self.visible_edges(edges)
+    }
+
+    pub(crate) fn select_nodes(
+        &self,
+        predicate: &Predicate,
+    ) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+        self.node_indices()
+            .into_par_iter()
+            .filter_map(|node_index| {
+                let attributes = self.graph.node_attributes(node_index.clone()).ok()?;
+
+                predicate
+                    .evaluate(attributes)
+                    .map(|matches| matches.then_some(node_index))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    pub(crate) fn select_edges(
+        &self,
+        predicate: &Predicate,
+    ) -> Result<Vec<EdgeIndex>, PyGraphsterError> {
+        self.edge_indices()
+            .into_par_iter()
+            .filter_map(|edge_index| {
+                let attributes = self.graph.edge_attributes(&edge_index).ok()?;
+
+                predicate
+                    .evaluate(attributes)
+                    .map(|matches| matches.then_some(edge_index))
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{GraphView, ViewDefinition};
+    use crate::{
+        errors::PyGraphsterError,
+        query::predicate::{ComparisonOperator, Predicate},
+    };
+    use graphster::{
+        datatypes::{AttributeKey, AttributeValue},
+        graph::DataGraph,
+    };
+    use std::collections::HashMap;
+
+    pub(crate) fn weight() -> AttributeKey {
+        AttributeKey::String("weight".to_string())
+    }
+
+    pub(crate) fn node(index: usize) -> AttributeKey {
+        AttributeKey::Usize(index)
+    }
+
+    pub(crate) fn index(node_index: &AttributeKey) -> usize {
+        match node_index {
+            AttributeKey::Usize(index) => *index,
+            _ => panic!("Unexpected node index {}", node_index),
+        }
+    }
+
+    pub(crate) fn graph(edges: &[(usize, usize, f64)]) -> DataGraph {
+        let mut graph = DataGraph::new();
+
+        for &(source, target, edge_weight) in edges {
+            for node_index in [source, target] {
+                if graph.node_attributes(node(node_index)).is_err() {
+                    graph
+                        .add_node(
+                            node(node_index),
+                            HashMap::<AttributeKey, AttributeValue>::new(),
+                        )
+                        .unwrap();
+                }
+            }
+
+            graph
+                .add_edge(
+                    node(source),
+                    node(target),
+                    HashMap::from([(weight(), AttributeValue::Float64(edge_weight))]),
+                )
+                .unwrap();
+        }
+
+        graph
+    }
+
+    pub(crate) fn assert_values(actual: Vec<(AttributeKey, f64)>, expected: &[(usize, f64)]) {
+        let actual = actual
+            .iter()
+            .map(|(node_index, value)| (index(node_index), *value))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+
+        for (node_index, value) in expected {
+            assert!(
+                (actual[node_index] - value).abs() < 1e-6,
+                "node {}: expected {}, got {}",
+                node_index,
+                value,
+                actual[node_index]
+            );
+        }
+    }
+
+    fn weight_above(value: AttributeValue) -> Predicate {
+        Predicate::Comparison {
+            key: weight(),
+            operator: ComparisonOperator::GreaterThan,
+            value,
+        }
+    }
+
+    #[test]
+    fn filtered_views_reject_mismatched_predicates() {
+        let graph = graph(&[(0, 1, 1.0), (1, 2, 3.0), (2, 0, 5.0)]);
+        let definition = ViewDefinition::default()
+            .filtered(
+                &graph,
+                None,
+                Some(weight_above(AttributeValue::Float64(2.0))),
+            )
+            .unwrap();
+
+        assert_eq!(GraphView::new(&graph, &definition).edge_count(), 2);
+        assert!(matches!(
+            definition.filtered(
+                &graph,
+                None,
+                Some(weight_above(AttributeValue::String("x".to_string())))
+            ),
+            Err(PyGraphsterError::QueryType(_))
+        ));
+        let definition = ViewDefinition::default()
+            .filtered(&graph, Some(weight_above(AttributeValue::Int64(0))), None)
+            .unwrap();
+
+        assert_eq!(GraphView::new(&graph, &definition).node_count(), 0);
+    }
+}
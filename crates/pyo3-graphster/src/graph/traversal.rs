@@ -0,0 +1,172 @@
+use super::{
+    neighbors::{neighbors, Direction},
+    topology::GraphView,
+};
+use crate::errors::PyGraphsterError;
+use graphster::datatypes::AttributeKey;
+use std::{
+    collections::{HashSet, VecDeque},
+    vec::IntoIter,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraversalEventKind {
+    Discover,
+    Finish,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TraversalEvent {
+    pub(crate) kind: TraversalEventKind,
+    pub(crate) node_index: AttributeKey,
+    pub(crate) depth: usize,
+    pub(crate) parent: Option<AttributeKey>,
+}
+
+impl TraversalEvent {
+    fn discover(node_index: AttributeKey, depth: usize, parent: Option<AttributeKey>) -> Self {
+        Self {
+            kind: TraversalEventKind::Discover,
+            node_index,
+            depth,
+            parent,
+        }
+    }
+
+    fn finish(self) -> Self {
+        Self {
+            kind: TraversalEventKind::Finish,
+            ..self
+        }
+    }
+}
+
+fn expand(
+    view: &GraphView<'_>,
+    event: &TraversalEvent,
+    direction: Direction,
+    max_depth: Option<usize>,
+) -> Result<Vec<AttributeKey>, PyGraphsterError> {
+    if max_depth.is_some_and(|max_depth| event.depth >= max_depth) {
+        return Ok(Vec::new());
+    }
+
+    neighbors(view, &event.node_index, direction)
+}
+
+#[derive(Debug)]
+enum Frontier {
+    Queue(VecDeque<TraversalEvent>),
+    Stack {
+        start: Option<TraversalEvent>,
+        stack: Vec<(TraversalEvent, IntoIter<AttributeKey>)>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) struct Traversal {
+    frontier: Frontier,
+    visited: HashSet<AttributeKey>,
+    direction: Direction,
+    max_depth: Option<usize>,
+}
+
+impl Traversal {
+    pub(crate) fn bfs(
+        view: &GraphView<'_>,
+        start: AttributeKey,
+        direction: Direction,
+        max_depth: Option<usize>,
+    ) -> Result<Self, PyGraphsterError> {
+        view.node_attributes(&start)?;
+
+        Ok(Self {
+            visited: HashSet::from([start.clone()]),
+            frontier: Frontier::Queue(VecDeque::from([TraversalEvent::discover(start, 0, None)])),
+            direction,
+            max_depth,
+        })
+    }
+
+    pub(crate) fn dfs(
+        view: &GraphView<'_>,
+        start: AttributeKey,
+        direction: Direction,
+        max_depth: Option<usize>,
+    ) -> Result<Self, PyGraphsterError> {
+        view.node_attributes(&start)?;
+
+        Ok(Self {
+            visited: HashSet::from([start.clone()]),
+            frontier: Frontier::Stack {
+                start: Some(TraversalEvent::discover(start, 0, None)),
+                stack: Vec::new(),
+            },
+            direction,
+            max_depth,
+        })
+    }
+
+    pub(crate) fn next(
+        &mut self,
+        view: &GraphView<'_>,
+    ) -> Result<Option<TraversalEvent>, PyGraphsterError> {
+        let (direction, max_depth) = (self.direction, self.max_depth);
+
+        match &mut self.frontier {
+            Frontier::Queue(queue) => {
+                let Some(event) = queue.pop_front() else {
+                    return Ok(None);
+                };
+
+                for neighbor_index in expand(view, &event, direction, max_depth)? {
+                    if self.visited.insert(neighbor_index.clone()) {
+                        queue.push_back(TraversalEvent::discover(
+                            neighbor_index,
+                            event.depth + 1,
+                            Some(event.node_index.clone()),
+                        ));
+                    }
+                }
+
+                Ok(Some(event))
+            }
+            Frontier::Stack { start, stack } => {
+                if let Some(start) = start.take() {
+                    let neighbor_indices = expand(view, &start, direction, max_depth)?;
+
+                    stack.push((start.clone(), neighbor_indices.into_iter()));
+
+                    return Ok(Some(start));
+                }
+
+                let Some((event, neighbor_indices)) = stack.last_mut() else {
+                    return Ok(None);
+                };
+
+                match neighbor_indices.find(|neighbor_index| !self.visited.contains(neighbor_index))
+                {
+                    Some(neighbor_index) => {
+                        self.visited.insert(neighbor_index.clone());
+
+                        let next = TraversalEvent::discover(
+                            neighbor_index,
+                            event.depth + 1,
+                            Some(event.node_index.clone()),
+                        );
+                        let next_neighbor_indices = expand(view, &next, direction, max_depth)?;
+
+                        stack.push((next.clone(), next_neighbor_indices.into_iter()));
+
+                        Ok(Some(next))
+                    }
+                    None => {
+                        let (event, _) = stack.pop().expect("Stack must not be empty");
+
+                        Ok(Some(event.finish()))
+                    }
+                }
+            }
+        }
+    }
+}
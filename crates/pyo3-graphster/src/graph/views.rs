@@ -0,0 +1,98 @@
+use super::{
+    PyDataGraph, PyEdgeDataIterator, PyEdgeIndex, PyEdgeIndexIterator, PyNodeDataIterator,
+    PyNodeIndex, PyNodeIndexIterator,
+};
+use graphster::datatypes::AttributeKey;
+use pyo3::{
+    exceptions::PyKeyError, pyclass, pymethods, types::PyAnyMethods, Bound, Py, PyAny, PyObject,
+    PyResult, Python,
+};
+
+#[pyclass]
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct PyNodesView(Py<PyDataGraph>);
+
+impl From<Py<PyDataGraph>> for PyNodesView {
+    fn from(value: Py<PyDataGraph>) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl PyNodesView {
+    fn __len__(&self, py: Python<'_>) -> usize {
+        self.0.borrow(py).graph.node_count()
+    }
+
+    fn __contains__(&self, node_index: &Bound<'_, PyAny>, py: Python<'_>) -> bool {
+        node_index.extract::<PyNodeIndex>().is_ok_and(|node_index| {
+            self.0
+                .borrow(py)
+                .graph
+                .contains_node(AttributeKey::from(node_index))
+        })
+    }
+
+    fn __getitem__(&self, node_index: &Bound<'_, PyAny>, py: Python<'_>) -> PyResult<PyObject> {
+        let graph = self.0.bind(py);
+
+        match node_index.extract::<PyNodeIndex>().map(AttributeKey::from) {
+            Ok(index) if graph.borrow().graph.contains_node(index.clone()) => {
+                PyDataGraph::node_attributes(graph, index.into(), py)
+            }
+            _ => Err(PyKeyError::new_err(node_index.clone().unbind())),
+        }
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyNodeIndexIterator {
+        PyDataGraph::node_indices(self.0.bind(py))
+    }
+
+    fn data(&self, py: Python<'_>) -> PyResult<PyNodeDataIterator> {
+        PyNodeDataIterator::new(self.0.bind(py).clone())
+    }
+}
+
+#[pyclass]
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct PyEdgesView(Py<PyDataGraph>);
+
+impl From<Py<PyDataGraph>> for PyEdgesView {
+    fn from(value: Py<PyDataGraph>) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl PyEdgesView {
+    fn __len__(&self, py: Python<'_>) -> usize {
+        self.0.borrow(py).graph.edge_count()
+    }
+
+    fn __contains__(&self, edge_index: &Bound<'_, PyAny>, py: Python<'_>) -> bool {
+        edge_index
+            .extract::<PyEdgeIndex>()
+            .is_ok_and(|edge_index| self.0.borrow(py).graph.contains_edge(&edge_index.0))
+    }
+
+    fn __getitem__(&self, edge_index: &Bound<'_, PyAny>, py: Python<'_>) -> PyResult<PyObject> {
+        let graph = self.0.bind(py);
+
+        match edge_index.extract::<PyEdgeIndex>() {
+            Ok(index) if graph.borrow().graph.contains_edge(&index.0) => {
+                PyDataGraph::edge_attributes(graph, index, py)
+            }
+            _ => Err(PyKeyError::new_err(edge_index.clone().unbind())),
+        }
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyEdgeIndexIterator {
+        PyDataGraph::edge_indices(self.0.bind(py))
+    }
+
+    fn data(&self, py: Python<'_>) -> PyResult<PyEdgeDataIterator> {
+        PyEdgeDataIterator::new(self.0.bind(py).clone())
+    }
+}
@@ -1,7 +1,10 @@
+#[cfg(feature = "polars")]
+mod dataframe;
 mod datatypes;
 mod errors;
 mod gil_hash_map;
 mod graph;
+mod query;
 
 pub use datatypes::{
     key::PyAttributeKey, value::PyAttributeValue, PyBoolean, PyFloat32, PyFloat64, PyInt128,
@@ -9,10 +12,16 @@ pub use datatypes::{
     PyUsize,
 };
 pub use errors::{
-    ConversionError, EdgeNotFoundError, GraphsterBaseError, NodeAlreadyExistsError,
-    NodeNotFoundError, PyGraphsterError,
+    AttributeNotFoundError, ConvergenceError, ConversionError, CycleError, EdgeNotFoundError,
+    GraphsterBaseError, NegativeCycleError, NegativeWeightError, NodeAlreadyExistsError,
+    NodeNotFoundError, PyGraphsterError, QueryParseError, QueryTypeError,
 };
-pub use graph::PyDataGraph;
+pub use graph::{
+    PyCriticalPathAnalysis, PyDataGraph, PyDataGraphView, PyEdgeDataIterator, PyEdgeIndexIterator,
+    PyEdgesView, PyHeuristic, PyNodeDataIterator, PyNodeIndexIterator, PyNodesView,
+    PyTraversalIterator,
+};
+pub use query::{attr, PyAttribute, PyQuery};
 
 use gil_hash_map::GILHashMap;
 use pyo3::{Bound, PyAny, PyResult};
@@ -0,0 +1,121 @@
+mod parser;
+pub(crate) mod predicate;
+
+use crate::{datatypes::key::PyAttributeKey, errors::PyGraphsterError, PyAttributeValue};
+use graphster::datatypes::AttributeKey;
+use parser::{parse_predicate, ParseError};
+use predicate::{ComparisonOperator, Predicate};
+use pyo3::{
+    basic::CompareOp, exceptions::PyTypeError, pyclass, pyfunction, pymethods, FromPyObject,
+    PyResult,
+};
+
+impl From<ParseError> for PyGraphsterError {
+    fn from(value: ParseError) -> Self {
+        Self::QueryParse {
+            message: value.message,
+            position: value.position,
+        }
+    }
+}
+
+impl From<CompareOp> for ComparisonOperator {
+    fn from(value: CompareOp) -> Self {
+        match value {
+            CompareOp::Eq => Self::Equal,
+            CompareOp::Ne => Self::NotEqual,
+            CompareOp::Lt => Self::LessThan,
+            CompareOp::Le => Self::LessThanOrEqual,
+            CompareOp::Gt => Self::GreaterThan,
+            CompareOp::Ge => Self::GreaterThanOrEqual,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct PyAttribute(AttributeKey);
+
+#[pymethods]
+impl PyAttribute {
+    #[new]
+    fn new(key: PyAttributeKey) -> Self {
+        Self(key.into())
+    }
+
+    fn __richcmp__(&self, other: PyAttributeValue, op: CompareOp) -> PyQuery {
+        Predicate::Comparison {
+            key: self.0.clone(),
+            operator: op.into(),
+            value: other.into(),
+        }
+        .into()
+    }
+
+    fn exists(&self) -> PyQuery {
+        Predicate::Exists(self.0.clone()).into()
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct PyQuery(pub(crate) Predicate);
+
+impl From<Predicate> for PyQuery {
+    fn from(value: Predicate) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl PyQuery {
+    #[staticmethod]
+    fn parse(expression: &str) -> PyResult<PyQuery> {
+        Ok(parse_predicate(expression)
+            .map_err(PyGraphsterError::from)?
+            .into())
+    }
+
+    fn __and__(&self, other: PyQuery) -> PyQuery {
+        Predicate::And(Box::new(self.0.clone()), Box::new(other.0)).into()
+    }
+
+    fn __or__(&self, other: PyQuery) -> PyQuery {
+        Predicate::Or(Box::new(self.0.clone()), Box::new(other.0)).into()
+    }
+
+    fn __invert__(&self) -> PyQuery {
+        Predicate::Not(Box::new(self.0.clone())).into()
+    }
+
+    fn __bool__(&self) -> PyResult<bool> {
+        Err(PyTypeError::new_err(
+            "The truth value of a PyQuery is ambiguous. Combine queries with '&', '|' and '~' \
+             instead of 'and', 'or' and 'not'",
+        ))
+    }
+}
+
+#[derive(FromPyObject)]
+pub(crate) enum PyQueryInput {
+    Query(PyQuery),
+    Expression(String),
+}
+
+impl PyQueryInput {
+    pub(crate) fn into_predicate(self) -> PyResult<Predicate> {
+        Ok(match self {
+            PyQueryInput::Query(query) => query.0,
+            PyQueryInput::Expression(expression) => {
+                parse_predicate(&expression).map_err(PyGraphsterError::from)?
+            }
+        })
+    }
+}
+
+#[pyfunction]
+pub fn attr(key: PyAttributeKey) -> PyAttribute {
+    PyAttribute(key.into())
+}
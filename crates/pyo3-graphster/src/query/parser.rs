@@ -0,0 +1,564 @@
+use super::predicate::{compare_attributevalues, ComparisonOperator, Predicate};
+use graphster::datatypes::{AttributeKey, AttributeValue};
+
+#[derive(Debug)]
+pub(crate) struct ParseError {
+    pub(crate) message: String,
+    pub(crate) position: usize,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Identifier(String),
+    String(String),
+    Integer(String),
+    Float(f64),
+    Operator(ComparisonOperator),
+    LeftParenthesis,
+    RightParenthesis,
+    LeftBracket,
+    RightBracket,
+    Comma,
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, ParseError> {
+    let characters = expression.chars().enumerate().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < characters.len() {
+        let (position, character) = characters[index];
+
+        if character.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        let kind = match character {
+            '(' => TokenKind::LeftParenthesis,
+            ')' => TokenKind::RightParenthesis,
+            '[' => TokenKind::LeftBracket,
+            ']' => TokenKind::RightBracket,
+            ',' => TokenKind::Comma,
+            '=' | '!' | '<' | '>' => {
+                let next = characters.get(index + 1).map(|(_, character)| *character);
+                let (operator, length) = match (character, next) {
+                    ('=', Some('=')) => (ComparisonOperator::Equal, 2),
+                    ('=', _) => (ComparisonOperator::Equal, 1),
+                    ('!', Some('=')) => (ComparisonOperator::NotEqual, 2),
+                    ('<', Some('=')) => (ComparisonOperator::LessThanOrEqual, 2),
+                    ('<', _) => (ComparisonOperator::LessThan, 1),
+                    ('>', Some('=')) => (ComparisonOperator::GreaterThanOrEqual, 2),
+                    ('>', _) => (ComparisonOperator::GreaterThan, 1),
+                    _ => return Err(ParseError::new("Unexpected character '!'", position)),
+                };
+
+                tokens.push(Token {
+                    kind: TokenKind::Operator(operator),
+                    position,
+                });
+                index += length;
+                continue;
+            }
+            '\'' | '"' => {
+                let mut value = String::new();
+                index += 1;
+
+                loop {
+                    match characters.get(index) {
+                        Some((_, '\\')) => {
+                            let Some((_, escaped)) = characters.get(index + 1) else {
+                                return Err(ParseError::new("Unterminated string", position));
+                            };
+                            value.push(*escaped);
+                            index += 2;
+                        }
+                        Some((_, quote)) if *quote == character => break,
+                        Some((_, other)) => {
+                            value.push(*other);
+                            index += 1;
+                        }
+                        None => return Err(ParseError::new("Unterminated string", position)),
+                    }
+                }
+
+                tokens.push(Token {
+                    kind: TokenKind::String(value),
+                    position,
+                });
+                index += 1;
+                continue;
+            }
+            character if character.is_ascii_digit() || character == '-' || character == '.' => {
+                let start = index;
+                index += 1;
+
+                while let Some((_, character)) = characters.get(index) {
+                    if character.is_ascii_alphanumeric()
+                        || matches!(character, '.' | '_')
+                        || (matches!(character, '+' | '-')
+                            && matches!(characters[index - 1].1, 'e' | 'E'))
+                    {
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                let text = characters[start..index]
+                    .iter()
+                    .map(|(_, character)| character)
+                    .filter(|character| **character != '_')
+                    .collect::<String>();
+
+                let kind = if text.chars().all(|character| character.is_ascii_digit())
+                    || (text.starts_with('-')
+                        && text.len() > 1
+                        && text[1..]
+                            .chars()
+                            .all(|character| character.is_ascii_digit()))
+                {
+                    TokenKind::Integer(text)
+                } else {
+                    TokenKind::Float(text.parse().map_err(|_| {
+                        ParseError::new(format!("Invalid number '{}'", text), position)
+                    })?)
+                };
+
+                tokens.push(Token { kind, position });
+                continue;
+            }
+            character if character.is_alphabetic() || character == '_' => {
+                let start = index;
+
+                while let Some((_, character)) = characters.get(index) {
+                    if character.is_alphanumeric() || *character == '_' {
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                tokens.push(Token {
+                    kind: TokenKind::Identifier(
+                        characters[start..index]
+                            .iter()
+                            .map(|(_, character)| character)
+                            .collect(),
+                    ),
+                    position,
+                });
+                continue;
+            }
+            character => {
+                return Err(ParseError::new(
+                    format!("Unexpected character '{}'", character),
+                    position,
+                ))
+            }
+        };
+
+        tokens.push(Token { kind, position });
+        index += 1;
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::End,
+        position: characters.len(),
+    });
+
+    Ok(tokens)
+}
+
+fn convert_integer(text: &str, position: usize) -> Result<AttributeValue, ParseError> {
+    if let Ok(value) = text.parse::<i64>() {
+        Ok(AttributeValue::Int64(value))
+    } else if let Ok(value) = text.parse::<u64>() {
+        Ok(AttributeValue::UInt64(value))
+    } else if let Ok(value) = text.parse::<i128>() {
+        Ok(AttributeValue::Int128(value))
+    } else if let Ok(value) = text.parse::<u128>() {
+        Ok(AttributeValue::UInt128(value))
+    } else {
+        Err(ParseError::new(
+            format!("Integer '{}' does not fit into 128 bits", text),
+            position,
+        ))
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+
+        if token.kind != TokenKind::End {
+            self.index += 1;
+        }
+
+        token
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Identifier(identifier) if identifier == keyword)
+    }
+
+    fn expect(&mut self, kind: TokenKind, description: &str) -> Result<Token, ParseError> {
+        let token = self.advance();
+
+        if token.kind == kind {
+            Ok(token)
+        } else {
+            Err(ParseError::new(
+                format!("Expected {}", description),
+                token.position,
+            ))
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Predicate, ParseError> {
+        let mut predicate = self.parse_and()?;
+
+        while self.is_keyword("or") {
+            self.advance();
+            predicate = Predicate::Or(Box::new(predicate), Box::new(self.parse_and()?));
+        }
+
+        Ok(predicate)
+    }
+
+    fn parse_and(&mut self) -> Result<Predicate, ParseError> {
+        let mut predicate = self.parse_not()?;
+
+        while self.is_keyword("and") {
+            self.advance();
+            predicate = Predicate::And(Box::new(predicate), Box::new(self.parse_not()?));
+        }
+
+        Ok(predicate)
+    }
+
+    fn parse_not(&mut self) -> Result<Predicate, ParseError> {
+        if self.is_keyword("not") {
+            self.advance();
+            return Ok(Predicate::Not(Box::new(self.parse_not()?)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Predicate, ParseError> {
+        if self.peek().kind == TokenKind::LeftParenthesis {
+            self.advance();
+            let predicate = self.parse_or()?;
+            self.expect(TokenKind::RightParenthesis, "')'")?;
+
+            return Ok(predicate);
+        }
+
+        let token = self.advance();
+
+        let key = match token.kind {
+            TokenKind::Identifier(identifier) if self.peek().kind == TokenKind::LeftParenthesis => {
+                return self.parse_function(&identifier, token.position);
+            }
+            TokenKind::Identifier(identifier) | TokenKind::String(identifier) => {
+                AttributeKey::String(identifier)
+            }
+            TokenKind::Integer(text) => {
+                AttributeKey::try_from(convert_integer(&text, token.position)?)
+                    .map_err(|_| ParseError::new("Expected an attribute key", token.position))?
+            }
+            _ => return Err(ParseError::new("Expected an attribute key", token.position)),
+        };
+
+        self.parse_comparison(key)
+    }
+
+    fn parse_function(&mut self, name: &str, position: usize) -> Result<Predicate, ParseError> {
+        self.expect(TokenKind::LeftParenthesis, "'('")?;
+
+        let token = self.advance();
+        let key = match token.kind {
+            TokenKind::Identifier(identifier) | TokenKind::String(identifier) => {
+                AttributeKey::String(identifier)
+            }
+            _ => return Err(ParseError::new("Expected an attribute key", token.position)),
+        };
+
+        self.expect(TokenKind::RightParenthesis, "')'")?;
+
+        match name {
+            "exists" => Ok(Predicate::Exists(key)),
+            "is_null" => Ok(Predicate::IsNull(key)),
+            _ => Err(ParseError::new(
+                format!("Unknown function '{}'", name),
+                position,
+            )),
+        }
+    }
+
+    fn parse_comparison(&mut self, key: AttributeKey) -> Result<Predicate, ParseError> {
+        if self.is_keyword("not") {
+            self.advance();
+
+            if !self.is_keyword("in") {
+                return Err(ParseError::new("Expected 'in'", self.peek().position));
+            }
+            self.advance();
+
+            return Ok(Predicate::Not(Box::new(Predicate::In {
+                key,
+                values: self.parse_list()?,
+            })));
+        }
+
+        if self.is_keyword("in") {
+            self.advance();
+
+            return Ok(Predicate::In {
+                key,
+                values: self.parse_list()?,
+            });
+        }
+
+        let token = self.advance();
+        let TokenKind::Operator(operator) = token.kind else {
+            return Err(ParseError::new(
+                "Expected a comparison operator",
+                token.position,
+            ));
+        };
+
+        let position = self.peek().position;
+        let value = self.parse_literal()?;
+
+        if matches!(value, AttributeValue::Null)
+            && !matches!(
+                operator,
+                ComparisonOperator::Equal | ComparisonOperator::NotEqual
+            )
+        {
+            return Err(ParseError::new("Cannot order by null", position));
+        }
+
+        Ok(Predicate::Comparison {
+            key,
+            operator,
+            value,
+        })
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<AttributeValue>, ParseError> {
+        self.expect(TokenKind::LeftBracket, "'['")?;
+
+        let mut values: Vec<AttributeValue> = Vec::new();
+
+        while self.peek().kind != TokenKind::RightBracket {
+            let position = self.peek().position;
+            let value = self.parse_literal()?;
+
+            if let Some(first_value) = values
+                .iter()
+                .find(|value| !matches!(value, AttributeValue::Null))
+            {
+                if !matches!(value, AttributeValue::Null)
+                    && compare_attributevalues(first_value, &value).is_none()
+                {
+                    return Err(ParseError::new(
+                        "List values must have comparable types",
+                        position,
+                    ));
+                }
+            }
+
+            values.push(value);
+
+            if self.peek().kind != TokenKind::Comma {
+                break;
+            }
+            self.advance();
+        }
+
+        self.expect(TokenKind::RightBracket, "']'")?;
+
+        Ok(values)
+    }
+
+    fn parse_literal(&mut self) -> Result<AttributeValue, ParseError> {
+        let token = self.advance();
+
+        Ok(match token.kind {
+            TokenKind::String(value) => AttributeValue::String(value),
+            TokenKind::Integer(text) => convert_integer(&text, token.position)?,
+            TokenKind::Float(value) => AttributeValue::Float64(value),
+            TokenKind::Identifier(identifier) => match identifier.as_str() {
+                "true" | "True" => AttributeValue::Boolean(true),
+                "false" | "False" => AttributeValue::Boolean(false),
+                "null" | "None" => AttributeValue::Null,
+                _ => {
+                    return Err(ParseError::new(
+                        format!("Expected a literal, found '{}'", identifier),
+                        token.position,
+                    ))
+                }
+            },
+            _ => return Err(ParseError::new("Expected a literal", token.position)),
+        })
+    }
+}
+
+pub(crate) fn parse_predicate(expression: &str) -> Result<Predicate, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        index: 0,
+    };
+
+    let predicate = parser.parse_or()?;
+
+    let token = parser.peek();
+    if token.kind != TokenKind::End {
+        return Err(ParseError::new("Unexpected trailing input", token.position));
+    }
+
+    Ok(predicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn attributes(values: &[(&str, AttributeValue)]) -> HashMap<AttributeKey, AttributeValue> {
+        values
+            .iter()
+            .map(|(key, value)| (AttributeKey::String(key.to_string()), value.clone()))
+            .collect()
+    }
+
+    fn evaluate(expression: &str, values: &[(&str, AttributeValue)]) -> bool {
+        parse_predicate(expression)
+            .unwrap()
+            .evaluate(&attributes(values))
+            .unwrap()
+    }
+
+    fn error(expression: &str) -> (String, usize) {
+        let error = parse_predicate(expression).unwrap_err();
+
+        (error.message, error.position)
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let values = [
+            ("a", AttributeValue::Int64(1)),
+            ("b", AttributeValue::Int64(0)),
+            ("c", AttributeValue::Int64(0)),
+        ];
+
+        assert!(evaluate("a == 1 or b == 1 and c == 1", &values));
+        assert!(!evaluate("(a == 1 or b == 1) and c == 1", &values));
+        assert!(!evaluate("b == 1 and c == 1 or b == 1", &values));
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        let values = [
+            ("a", AttributeValue::Int64(0)),
+            ("b", AttributeValue::Int64(0)),
+        ];
+
+        assert!(!evaluate("not a == 1 and b == 1", &values));
+        assert!(evaluate("not (a == 1 and b == 1)", &values));
+        assert!(!evaluate("not not a == 1", &values));
+    }
+
+    #[test]
+    fn in_lists_compare_numbers_across_types() {
+        let values = [("a", AttributeValue::Int32(3))];
+
+        assert!(evaluate("a in [1, 2.5, 3.0]", &values));
+        assert!(!evaluate("a in []", &values));
+        assert!(evaluate("a not in [1, 2]", &values));
+        assert!(!evaluate("missing in [3]", &values));
+        assert_eq!(
+            error("a in [1, 'x']"),
+            ("List values must have comparable types".to_string(), 9)
+        );
+        assert_eq!(error("a not [1]"), ("Expected 'in'".to_string(), 6));
+    }
+
+    #[test]
+    fn is_null_matches_missing_and_null_attributes() {
+        assert!(evaluate("is_null(a)", &[]));
+        assert!(evaluate("is_null(a)", &[("a", AttributeValue::Null)]));
+        assert!(!evaluate("is_null(a)", &[("a", AttributeValue::Int64(1))]));
+        assert!(!evaluate("exists(a)", &[]));
+        assert!(evaluate("a == null", &[("a", AttributeValue::Null)]));
+        assert_eq!(
+            error("is_empty(a)"),
+            ("Unknown function 'is_empty'".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn strings_unescape_quotes_and_backslashes() {
+        let values = [(
+            "name",
+            AttributeValue::String(r#"it's a "b\c""#.to_string()),
+        )];
+
+        assert!(evaluate(r#"name == 'it\'s a "b\\c"'"#, &values));
+        assert!(evaluate(r#"name == "it's a \"b\\c\"""#, &values));
+        assert!(evaluate(r#"'name' == "it's a \"b\\c\"""#, &values));
+        assert_eq!(
+            error(r#"name == 'open\'"#),
+            ("Unterminated string".to_string(), 8)
+        );
+    }
+
+    #[test]
+    fn errors_report_character_offsets() {
+        assert_eq!(error("a == "), ("Expected a literal".to_string(), 5));
+        assert_eq!(
+            error("a == 1 and"),
+            ("Expected an attribute key".to_string(), 10)
+        );
+        assert_eq!(error("(a == 1"), ("Expected ')'".to_string(), 7));
+        assert_eq!(error("a ! 1"), ("Unexpected character '!'".to_string(), 2));
+        assert_eq!(error("a < null"), ("Cannot order by null".to_string(), 4));
+        assert_eq!(
+            error("a == 1 b"),
+            ("Unexpected trailing input".to_string(), 7)
+        );
+        assert_eq!(
+            error("größe == 1 @"),
+            ("Unexpected character '@'".to_string(), 11)
+        );
+    }
+}
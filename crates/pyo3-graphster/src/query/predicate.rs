@@ -0,0 +1,208 @@
+use crate::errors::PyGraphsterError;
+use graphster::datatypes::{AttributeKey, AttributeValue};
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Predicate {
+    Comparison {
+        key: AttributeKey,
+        operator: ComparisonOperator,
+        value: AttributeValue,
+    },
+    In {
+        key: AttributeKey,
+        values: Vec<AttributeValue>,
+    },
+    Exists(AttributeKey),
+    IsNull(AttributeKey),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+}
+
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+fn convert_attributevalue_to_number(value: &AttributeValue) -> Option<Number> {
+    Some(match value {
+        AttributeValue::Float32(value) => Number::Float(*value as f64),
+        AttributeValue::Float64(value) => Number::Float(*value),
+        AttributeValue::Int128(value) => Number::Signed(*value),
+        AttributeValue::Int16(value) => Number::Signed(*value as i128),
+        AttributeValue::Int32(value) => Number::Signed(*value as i128),
+        AttributeValue::Int64(value) => Number::Signed(*value as i128),
+        AttributeValue::Int8(value) => Number::Signed(*value as i128),
+        AttributeValue::UInt128(value) => Number::Unsigned(*value),
+        AttributeValue::UInt16(value) => Number::Unsigned(*value as u128),
+        AttributeValue::UInt32(value) => Number::Unsigned(*value as u128),
+        AttributeValue::UInt64(value) => Number::Unsigned(*value as u128),
+        AttributeValue::UInt8(value) => Number::Unsigned(*value as u128),
+        AttributeValue::Usize(value) => Number::Unsigned(*value as u128),
+        _ => return None,
+    })
+}
+
+impl From<Number> for f64 {
+    fn from(value: Number) -> Self {
+        match value {
+            Number::Signed(value) => value as f64,
+            Number::Unsigned(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+pub(crate) fn convert_attributevalue_to_f64(value: &AttributeValue) -> Option<f64> {
+    convert_attributevalue_to_number(value).map(f64::from)
+}
+
+fn compare_numbers(left: Number, right: Number) -> Option<Ordering> {
+    match (left, right) {
+        (Number::Signed(left), Number::Signed(right)) => Some(left.cmp(&right)),
+        (Number::Unsigned(left), Number::Unsigned(right)) => Some(left.cmp(&right)),
+        (Number::Signed(left), Number::Unsigned(right)) => Some(if left < 0 {
+            Ordering::Less
+        } else {
+            (left as u128).cmp(&right)
+        }),
+        (Number::Unsigned(left), Number::Signed(right)) => Some(if right < 0 {
+            Ordering::Greater
+        } else {
+            left.cmp(&(right as u128))
+        }),
+        (Number::Float(left), Number::Float(right)) => left.partial_cmp(&right),
+        (Number::Float(left), Number::Signed(right)) => left.partial_cmp(&(right as f64)),
+        (Number::Float(left), Number::Unsigned(right)) => left.partial_cmp(&(right as f64)),
+        (Number::Signed(left), Number::Float(right)) => (left as f64).partial_cmp(&right),
+        (Number::Unsigned(left), Number::Float(right)) => (left as f64).partial_cmp(&right),
+    }
+}
+
+pub(crate) fn compare_attributevalues(
+    left: &AttributeValue,
+    right: &AttributeValue,
+) -> Option<Ordering> {
+    match (left, right) {
+        (AttributeValue::Boolean(left), AttributeValue::Boolean(right)) => Some(left.cmp(right)),
+        (AttributeValue::String(left), AttributeValue::String(right)) => Some(left.cmp(right)),
+        (AttributeValue::Null, AttributeValue::Null) => Some(Ordering::Equal),
+        _ => compare_numbers(
+            convert_attributevalue_to_number(left)?,
+            convert_attributevalue_to_number(right)?,
+        ),
+    }
+}
+
+fn type_name(value: &AttributeValue) -> &'static str {
+    match value {
+        AttributeValue::Boolean(_) => "bool",
+        AttributeValue::String(_) => "str",
+        AttributeValue::Float32(_) | AttributeValue::Float64(_) => "float",
+        AttributeValue::Null => "None",
+        _ => "int",
+    }
+}
+
+fn check_comparable(
+    key: &AttributeKey,
+    attribute: &AttributeValue,
+    value: &AttributeValue,
+) -> Result<(), PyGraphsterError> {
+    let comparable = match (attribute, value) {
+        (AttributeValue::Null, _) | (_, AttributeValue::Null) => true,
+        (AttributeValue::Boolean(_), AttributeValue::Boolean(_)) => true,
+        (AttributeValue::String(_), AttributeValue::String(_)) => true,
+        _ => {
+            convert_attributevalue_to_number(attribute).is_some()
+                && convert_attributevalue_to_number(value).is_some()
+        }
+    };
+
+    if comparable {
+        Ok(())
+    } else {
+        Err(PyGraphsterError::QueryType(format!(
+            "Cannot compare attribute {} of type {} with a value of type {}",
+            key,
+            type_name(attribute),
+            type_name(value)
+        )))
+    }
+}
+
+impl Predicate {
+    pub(crate) fn evaluate(
+        &self,
+        attributes: &HashMap<AttributeKey, AttributeValue>,
+    ) -> Result<bool, PyGraphsterError> {
+        Ok(match self {
+            Predicate::Comparison {
+                key,
+                operator,
+                value,
+            } => {
+                let Some(attribute) = attributes.get(key) else {
+                    return Ok(false);
+                };
+                check_comparable(key, attribute, value)?;
+                let ordering = compare_attributevalues(attribute, value);
+
+                match operator {
+                    ComparisonOperator::Equal => ordering == Some(Ordering::Equal),
+                    ComparisonOperator::NotEqual => ordering != Some(Ordering::Equal),
+                    ComparisonOperator::LessThan => ordering == Some(Ordering::Less),
+                    ComparisonOperator::LessThanOrEqual => {
+                        matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+                    }
+                    ComparisonOperator::GreaterThan => ordering == Some(Ordering::Greater),
+                    ComparisonOperator::GreaterThanOrEqual => {
+                        matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+                    }
+                }
+            }
+            Predicate::In { key, values } => {
+                let Some(attribute) = attributes.get(key) else {
+                    return Ok(false);
+                };
+
+                for value in values {
+                    check_comparable(key, attribute, value)?;
+
+                    if compare_attributevalues(attribute, value) == Some(Ordering::Equal) {
+                        return Ok(true);
+                    }
+                }
+
+                false
+            }
+            Predicate::Exists(key) => attributes.contains_key(key),
+            Predicate::IsNull(key) => attributes
+                .get(key)
+                .is_none_or(|attribute| matches!(attribute, AttributeValue::Null)),
+            Predicate::And(left, right) => {
+                left.evaluate(attributes)? && right.evaluate(attributes)?
+            }
+            Predicate::Or(left, right) => {
+                left.evaluate(attributes)? || right.evaluate(attributes)?
+            }
+            Predicate::Not(predicate) => !predicate.evaluate(attributes)?,
+        })
+    }
+
+    pub(crate) fn matches(&self, attributes: &HashMap<AttributeKey, AttributeValue>) -> bool {
+        self.evaluate(attributes).unwrap_or(false)
+    }
+}
@@ -1,120 +1,902 @@
-from typing import Dict, List, Tuple, TypeAlias, Union
+# This file is generated by scripts/generate_stubs.py. Do not edit it by hand.
+
+from typing import Callable, Dict, Iterable, List, Literal, Optional, Tuple, TypeAlias, Union
+
+import polars as pl
+
+PyAttributeValue: TypeAlias = Union[
+    int,
+    bool,
+    str,
+    float,
+    PyFloat32,
+    PyFloat64,
+    PyInt128,
+    PyInt16,
+    PyInt32,
+    PyInt64,
+    PyInt8,
+    PyString,
+    PyUInt128,
+    PyUInt16,
+    PyUInt32,
+    PyUInt64,
+    PyUInt8,
+    PyUsize,
+    None,
+]
+
+PyAttributeKey: TypeAlias = Union[
+    int,
+    bool,
+    str,
+    PyInt128,
+    PyInt16,
+    PyInt32,
+    PyInt64,
+    PyInt8,
+    PyString,
+    PyUInt128,
+    PyUInt16,
+    PyUInt32,
+    PyUInt64,
+    PyUInt8,
+    PyUsize,
+]
+
+PyNodeIndex: TypeAlias = PyAttributeKey
+PyEdgeIndex: TypeAlias = Union[int, PyUsize]
+PyAttributes: TypeAlias = Dict[PyAttributeKey, PyAttributeValue]
+PyTraversalStep: TypeAlias = Tuple[PyNodeIndex, int, Optional[PyNodeIndex]]
+PyTraversalEvent: TypeAlias = Tuple[
+    Literal["discover", "finish"], PyNodeIndex, int, Optional[PyNodeIndex]
+]
 
 class PyBoolean:
     def __init__(self, value: bool) -> None: ...
     def value(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyFloat32:
     def __init__(self, value: float) -> None: ...
     def value(self) -> float: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __float__(self) -> float: ...
+    def __int__(self) -> int: ...
 
 class PyFloat64:
     def __init__(self, value: float) -> None: ...
     def value(self) -> float: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __float__(self) -> float: ...
+    def __int__(self) -> int: ...
 
 class PyInt128:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyInt16:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyInt32:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyInt64:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyInt8:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyString:
     def __init__(self, value: str) -> None: ...
     def value(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __len__(self) -> int: ...
 
 class PyUInt128:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyUInt16:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyUInt32:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyUInt64:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyUInt8:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
 class PyUsize:
     def __init__(self, value: int) -> None: ...
     def value(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __gt__(self, other: object) -> bool: ...
+    def __ge__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __float__(self) -> float: ...
 
-PyAttributeValue: TypeAlias = Union[
-    int,
-    bool,
-    str,
-    float,
-    PyFloat32,
-    PyFloat64,
-    PyInt128,
-    PyInt16,
-    PyInt32,
-    PyInt64,
-    PyInt8,
-    PyString,
-    PyUInt128,
-    PyUInt16,
-    PyUInt32,
-    PyUInt64,
-    PyUInt8,
-    PyUsize,
-    None,
-]
+class PyHeuristic:
+    @staticmethod
+    def euclidean(x: PyAttributeKey, y: PyAttributeKey) -> PyHeuristic: ...
+    @staticmethod
+    def haversine(
+        latitude: PyAttributeKey,
+        longitude: PyAttributeKey,
+        radius: float = ...,
+    ) -> PyHeuristic: ...
 
-PyAttributeKey: TypeAlias = Union[
-    int,
-    bool,
-    str,
-    PyInt128,
-    PyInt16,
-    PyInt32,
-    PyInt64,
-    PyInt8,
-    PyString,
-    PyUInt128,
-    PyUInt16,
-    PyUInt32,
-    PyUInt64,
-    PyUInt8,
-    PyUsize,
-]
+class PyCriticalPathAnalysis:
+    @property
+    def duration(self) -> float: ...
+    @property
+    def critical_path(self) -> List[PyNodeIndex]: ...
+    @property
+    def earliest_start(self) -> Dict[PyNodeIndex, float]: ...
+    @property
+    def earliest_finish(self) -> Dict[PyNodeIndex, float]: ...
+    @property
+    def latest_start(self) -> Dict[PyNodeIndex, float]: ...
+    @property
+    def latest_finish(self) -> Dict[PyNodeIndex, float]: ...
+    @property
+    def slack(self) -> Dict[PyNodeIndex, float]: ...
 
-PyNodeIndex: TypeAlias = PyAttributeKey
-PyAttributes: TypeAlias = Dict[PyAttributeKey, PyAttributeValue]
+class PyDataGraphView:
+    def reversed_view(self) -> PyDataGraphView: ...
+    def undirected_view(self) -> PyDataGraphView: ...
+    def filtered_view(
+        self,
+        node_query: Optional[Union[PyQuery, str]] = ...,
+        edge_query: Optional[Union[PyQuery, str]] = ...,
+    ) -> PyDataGraphView: ...
+    def node_count(self) -> int: ...
+    def edge_count(self) -> int: ...
+    def node_indices(self) -> PyNodeIndexIterator: ...
+    def edge_indices(self) -> PyEdgeIndexIterator: ...
+    def contains_node(self, node_index: PyNodeIndex) -> bool: ...
+    def contains_edge(self, edge_index: PyEdgeIndex) -> bool: ...
+    def node_attributes(self, node_index: PyNodeIndex) -> PyAttributes: ...
+    def edge_attributes(self, edge_index: PyEdgeIndex) -> PyAttributes: ...
+    def edge_endpoints(
+        self,
+        edge_index: PyEdgeIndex,
+    ) -> Tuple[PyNodeIndex, PyNodeIndex]: ...
+    def edge_endpoints_many(
+        self,
+        edge_indices: Iterable[PyEdgeIndex],
+    ) -> List[Tuple[PyNodeIndex, PyNodeIndex]]: ...
+    def select_nodes(self, query: Union[PyQuery, str]) -> PyNodeIndexIterator: ...
+    def select_edges(self, query: Union[PyQuery, str]) -> PyEdgeIndexIterator: ...
+    def successors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def predecessors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def neighbors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def in_degree(self, node_index: PyNodeIndex) -> int: ...
+    def out_degree(self, node_index: PyNodeIndex) -> int: ...
+    def degree(self, node_index: PyNodeIndex) -> int: ...
+    def in_degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def out_degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def bfs(
+        self,
+        start: PyNodeIndex,
+        direction: Literal["out", "in", "both"] = ...,
+        max_depth: Optional[int] = ...,
+        with_details: bool = ...,
+    ) -> PyTraversalIterator: ...
+    def dfs(
+        self,
+        start: PyNodeIndex,
+        direction: Literal["out", "in", "both"] = ...,
+        max_depth: Optional[int] = ...,
+        with_details: bool = ...,
+        with_events: bool = ...,
+    ) -> PyTraversalIterator: ...
+    def shortest_path(
+        self,
+        source: PyNodeIndex,
+        target: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Optional[List[PyNodeIndex]]: ...
+    def shortest_path_lengths(
+        self,
+        source: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def all_pairs_shortest_path_lengths(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, Dict[PyNodeIndex, float]]: ...
+    def astar_path(
+        self,
+        source: PyNodeIndex,
+        target: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+        heuristic: Optional[Union[PyHeuristic, Callable[[PyNodeIndex, PyNodeIndex], float]]] = ...,
+    ) -> Optional[List[PyNodeIndex]]: ...
+    def weakly_connected_components(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> List[List[PyNodeIndex]]: ...
+    def strongly_connected_components(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> List[List[PyNodeIndex]]: ...
+    def is_weakly_connected(self) -> bool: ...
+    def is_strongly_connected(self) -> bool: ...
+    def condensation(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> Tuple[PyDataGraph, Dict[PyNodeIndex, List[PyNodeIndex]]]: ...
+    def topological_sort(self) -> PyNodeIndexIterator: ...
+    def lexicographical_topological_sort(
+        self,
+        key: Optional[PyAttributeKey] = ...,
+    ) -> PyNodeIndexIterator: ...
+    def find_cycle(self) -> Optional[List[PyNodeIndex]]: ...
+    def is_dag(self) -> bool: ...
+    def ancestors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def descendants(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def dag_longest_path(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        weight_source: Literal["edge", "node"] = ...,
+    ) -> List[PyNodeIndex]: ...
+    def critical_path_analysis(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        weight_source: Literal["edge", "node"] = ...,
+        attribute_prefix: Optional[str] = ...,
+    ) -> PyCriticalPathAnalysis: ...
+    def pagerank(
+        self,
+        alpha: float = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        personalization: Optional[Union[Dict[PyNodeIndex, float], PyAttributeKey]] = ...,
+        tol: float = ...,
+        max_iter: int = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def degree_centrality(
+        self,
+        direction: Literal["out", "in", "both"] = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def closeness_centrality(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        wf_improved: bool = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def betweenness_centrality(
+        self,
+        k: Optional[int] = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        endpoints: bool = ...,
+        seed: Optional[int] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def edge_betweenness_centrality(
+        self,
+        k: Optional[int] = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        seed: Optional[int] = ...,
+    ) -> Dict[PyEdgeIndex, float]: ...
+    def eigenvector_centrality(
+        self,
+        max_iter: int = ...,
+        tol: float = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def katz_centrality(
+        self,
+        alpha: float = ...,
+        beta: float = ...,
+        max_iter: int = ...,
+        tol: float = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def hits(
+        self,
+        max_iter: int = ...,
+        tol: float = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Tuple[Dict[PyNodeIndex, float], Dict[PyNodeIndex, float]]: ...
+    @property
+    def graph(self) -> PyDataGraph: ...
+
+class PyNodeIndexIterator:
+    def __iter__(self) -> PyNodeIndexIterator: ...
+    def __next__(self) -> PyNodeIndex: ...
+    def __length_hint__(self) -> int: ...
+
+class PyEdgeIndexIterator:
+    def __iter__(self) -> PyEdgeIndexIterator: ...
+    def __next__(self) -> PyEdgeIndex: ...
+    def __length_hint__(self) -> int: ...
+
+class PyNodeDataIterator:
+    def __iter__(self) -> PyNodeDataIterator: ...
+    def __next__(self) -> Tuple[PyNodeIndex, PyAttributes]: ...
+    def __length_hint__(self) -> int: ...
+
+class PyEdgeDataIterator:
+    def __iter__(self) -> PyEdgeDataIterator: ...
+    def __next__(self) -> Tuple[PyEdgeIndex, PyAttributes]: ...
+    def __length_hint__(self) -> int: ...
+
+class PyTraversalIterator:
+    def __iter__(self) -> PyTraversalIterator: ...
+    def __next__(self) -> Union[PyNodeIndex, PyTraversalStep, PyTraversalEvent]: ...
 
 class PyDataGraph:
-    def __init__(self) -> None: ...
+    native_types: bool
+    def reversed_view(self) -> PyDataGraphView: ...
+    def undirected_view(self) -> PyDataGraphView: ...
+    def filtered_view(
+        self,
+        node_query: Optional[Union[PyQuery, str]] = ...,
+        edge_query: Optional[Union[PyQuery, str]] = ...,
+    ) -> PyDataGraphView: ...
+    def node_count(self) -> int: ...
+    def edge_count(self) -> int: ...
+    def node_indices(self) -> PyNodeIndexIterator: ...
+    def edge_indices(self) -> PyEdgeIndexIterator: ...
+    def contains_node(self, node_index: PyNodeIndex) -> bool: ...
+    def contains_edge(self, edge_index: PyEdgeIndex) -> bool: ...
+    def node_attributes(self, node_index: PyNodeIndex) -> PyAttributes: ...
+    def edge_attributes(self, edge_index: PyEdgeIndex) -> PyAttributes: ...
+    def edge_endpoints(
+        self,
+        edge_index: PyEdgeIndex,
+    ) -> Tuple[PyNodeIndex, PyNodeIndex]: ...
+    def edge_endpoints_many(
+        self,
+        edge_indices: Iterable[PyEdgeIndex],
+    ) -> List[Tuple[PyNodeIndex, PyNodeIndex]]: ...
+    def select_nodes(self, query: Union[PyQuery, str]) -> PyNodeIndexIterator: ...
+    def select_edges(self, query: Union[PyQuery, str]) -> PyEdgeIndexIterator: ...
+    def successors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def predecessors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def neighbors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def in_degree(self, node_index: PyNodeIndex) -> int: ...
+    def out_degree(self, node_index: PyNodeIndex) -> int: ...
+    def degree(self, node_index: PyNodeIndex) -> int: ...
+    def in_degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def out_degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def degrees(
+        self,
+        node_indices: Optional[List[PyNodeIndex]] = ...,
+    ) -> Dict[PyNodeIndex, int]: ...
+    def bfs(
+        self,
+        start: PyNodeIndex,
+        direction: Literal["out", "in", "both"] = ...,
+        max_depth: Optional[int] = ...,
+        with_details: bool = ...,
+    ) -> PyTraversalIterator: ...
+    def dfs(
+        self,
+        start: PyNodeIndex,
+        direction: Literal["out", "in", "both"] = ...,
+        max_depth: Optional[int] = ...,
+        with_details: bool = ...,
+        with_events: bool = ...,
+    ) -> PyTraversalIterator: ...
+    def shortest_path(
+        self,
+        source: PyNodeIndex,
+        target: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Optional[List[PyNodeIndex]]: ...
+    def shortest_path_lengths(
+        self,
+        source: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def all_pairs_shortest_path_lengths(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, Dict[PyNodeIndex, float]]: ...
+    def astar_path(
+        self,
+        source: PyNodeIndex,
+        target: PyNodeIndex,
+        weight: Optional[PyAttributeKey] = ...,
+        heuristic: Optional[Union[PyHeuristic, Callable[[PyNodeIndex, PyNodeIndex], float]]] = ...,
+    ) -> Optional[List[PyNodeIndex]]: ...
+    def weakly_connected_components(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> List[List[PyNodeIndex]]: ...
+    def strongly_connected_components(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> List[List[PyNodeIndex]]: ...
+    def is_weakly_connected(self) -> bool: ...
+    def is_strongly_connected(self) -> bool: ...
+    def condensation(
+        self,
+        attribute: Optional[PyAttributeKey] = ...,
+    ) -> Tuple[PyDataGraph, Dict[PyNodeIndex, List[PyNodeIndex]]]: ...
+    def topological_sort(self) -> PyNodeIndexIterator: ...
+    def lexicographical_topological_sort(
+        self,
+        key: Optional[PyAttributeKey] = ...,
+    ) -> PyNodeIndexIterator: ...
+    def find_cycle(self) -> Optional[List[PyNodeIndex]]: ...
+    def is_dag(self) -> bool: ...
+    def ancestors(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def descendants(self, node_index: PyNodeIndex) -> PyNodeIndexIterator: ...
+    def dag_longest_path(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        weight_source: Literal["edge", "node"] = ...,
+    ) -> List[PyNodeIndex]: ...
+    def critical_path_analysis(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        weight_source: Literal["edge", "node"] = ...,
+        attribute_prefix: Optional[str] = ...,
+    ) -> PyCriticalPathAnalysis: ...
+    def pagerank(
+        self,
+        alpha: float = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        personalization: Optional[Union[Dict[PyNodeIndex, float], PyAttributeKey]] = ...,
+        tol: float = ...,
+        max_iter: int = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def degree_centrality(
+        self,
+        direction: Literal["out", "in", "both"] = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def closeness_centrality(
+        self,
+        weight: Optional[PyAttributeKey] = ...,
+        wf_improved: bool = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def betweenness_centrality(
+        self,
+        k: Optional[int] = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        endpoints: bool = ...,
+        seed: Optional[int] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def edge_betweenness_centrality(
+        self,
+        k: Optional[int] = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        seed: Optional[int] = ...,
+    ) -> Dict[PyEdgeIndex, float]: ...
+    def eigenvector_centrality(
+        self,
+        max_iter: int = ...,
+        tol: float = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def katz_centrality(
+        self,
+        alpha: float = ...,
+        beta: float = ...,
+        max_iter: int = ...,
+        tol: float = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Dict[PyNodeIndex, float]: ...
+    def hits(
+        self,
+        max_iter: int = ...,
+        tol: float = ...,
+        normalized: bool = ...,
+        weight: Optional[PyAttributeKey] = ...,
+    ) -> Tuple[Dict[PyNodeIndex, float], Dict[PyNodeIndex, float]]: ...
+    def __init__(self, native_types: bool = ...) -> None: ...
     @staticmethod
     def from_nodes(
         nodes: List[Tuple[PyNodeIndex, PyAttributes]],
+        native_types: bool = ...,
     ) -> PyDataGraph: ...
     @staticmethod
     def from_nodes_and_edges(
         nodes: List[Tuple[PyNodeIndex, PyAttributes]],
         edges: List[Tuple[PyNodeIndex, PyNodeIndex, PyAttributes]],
+        native_types: bool = ...,
+    ) -> PyDataGraph: ...
+    @staticmethod
+    def from_polars(
+        nodes_dataframe: pl.DataFrame,
+        edges_dataframe: pl.DataFrame,
+        node_index_column: str,
+        source_column: str,
+        target_column: str,
+        native_types: bool = ...,
     ) -> PyDataGraph: ...
     def add_node(self, node_index: PyNodeIndex, attributes: PyAttributes) -> None: ...
     def add_nodes(self, nodes: List[Tuple[PyNodeIndex, PyAttributes]]) -> None: ...
-    def node_count(self) -> int: ...
-    def edge_count(self) -> int: ...
+    def add_edge(
+        self,
+        source_node_index: PyNodeIndex,
+        target_node_index: PyNodeIndex,
+        attributes: PyAttributes,
+    ) -> PyEdgeIndex: ...
+    def add_edges(
+        self,
+        edges: List[Tuple[PyNodeIndex, PyNodeIndex, PyAttributes]],
+    ) -> List[PyEdgeIndex]: ...
+    def remove_node(self, node_index: PyNodeIndex) -> PyAttributes: ...
+    def remove_edge(self, edge_index: PyEdgeIndex) -> PyAttributes: ...
+    def remove_nodes(
+        self,
+        node_indices: Iterable[PyNodeIndex],
+    ) -> Dict[PyNodeIndex, PyAttributes]: ...
+    def remove_edges(
+        self,
+        edge_indices: Iterable[PyEdgeIndex],
+    ) -> Dict[PyEdgeIndex, PyAttributes]: ...
+    def rewire_edge(
+        self,
+        edge_index: PyEdgeIndex,
+        source_node_index: PyNodeIndex,
+        target_node_index: PyNodeIndex,
+    ) -> None: ...
+    def reverse_edge(self, edge_index: PyEdgeIndex) -> None: ...
+    def set_node_attribute(
+        self,
+        node_index: PyNodeIndex,
+        key: PyAttributeKey,
+        value: PyAttributeValue,
+    ) -> None: ...
+    def update_node_attributes(
+        self,
+        node_index: PyNodeIndex,
+        attributes: PyAttributes,
+    ) -> None: ...
+    def delete_node_attribute(
+        self,
+        node_index: PyNodeIndex,
+        key: PyAttributeKey,
+    ) -> PyAttributeValue: ...
+    def set_edge_attribute(
+        self,
+        edge_index: PyEdgeIndex,
+        key: PyAttributeKey,
+        value: PyAttributeValue,
+    ) -> None: ...
+    def update_edge_attributes(
+        self,
+        edge_index: PyEdgeIndex,
+        attributes: PyAttributes,
+    ) -> None: ...
+    def delete_edge_attribute(
+        self,
+        edge_index: PyEdgeIndex,
+        key: PyAttributeKey,
+    ) -> PyAttributeValue: ...
+    def set_node_attribute_many(
+        self,
+        key: PyAttributeKey,
+        values: Dict[PyNodeIndex, PyAttributeValue],
+    ) -> None: ...
+    def set_edge_attribute_many(
+        self,
+        key: PyAttributeKey,
+        values: Dict[PyEdgeIndex, PyAttributeValue],
+    ) -> None: ...
+    def set_node_attribute_from_polars(
+        self,
+        key: PyAttributeKey,
+        dataframe: pl.DataFrame,
+        node_index_column: str,
+        value_column: str,
+    ) -> None: ...
+    def set_edge_attribute_from_polars(
+        self,
+        key: PyAttributeKey,
+        dataframe: pl.DataFrame,
+        edge_index_column: str,
+        value_column: str,
+    ) -> None: ...
+    @property
+    def nodes(self) -> PyNodesView: ...
+    @property
+    def edges(self) -> PyEdgesView: ...
+    def subgraph(
+        self,
+        node_indices: Iterable[PyNodeIndex],
+        preserve_edge_indices: bool = ...,
+    ) -> PyDataGraph: ...
+    def edge_subgraph(
+        self,
+        edge_indices: Iterable[PyEdgeIndex],
+        preserve_edge_indices: bool = ...,
+    ) -> PyDataGraph: ...
+    def nodes_to_polars(self, node_index_column: str = ...) -> pl.DataFrame: ...
+    def edges_to_polars(
+        self,
+        edge_index_column: str = ...,
+        source_column: str = ...,
+        target_column: str = ...,
+    ) -> pl.DataFrame: ...
+    def pagerank_to_polars(
+        self,
+        alpha: float = ...,
+        weight: Optional[PyAttributeKey] = ...,
+        personalization: Optional[Union[Dict[PyNodeIndex, float], PyAttributeKey]] = ...,
+        tol: float = ...,
+        max_iter: int = ...,
+        node_index_column: str = ...,
+        score_column: str = ...,
+    ) -> pl.DataFrame: ...
+    def incoming_edge_indices(self, node_index: PyNodeIndex) -> PyEdgeIndexIterator: ...
+    def outgoing_edge_indices(self, node_index: PyNodeIndex) -> PyEdgeIndexIterator: ...
+    def edges_connecting(
+        self,
+        source_node_index: PyNodeIndex,
+        target_node_index: PyNodeIndex,
+    ) -> PyEdgeIndexIterator: ...
+
+class PyNodesView:
+    def __len__(self) -> int: ...
+    def __contains__(self, node_index: PyNodeIndex) -> bool: ...
+    def __getitem__(self, node_index: PyNodeIndex) -> PyAttributes: ...
+    def __iter__(self) -> PyNodeIndexIterator: ...
+    def data(self) -> PyNodeDataIterator: ...
+
+class PyEdgesView:
+    def __len__(self) -> int: ...
+    def __contains__(self, edge_index: PyEdgeIndex) -> bool: ...
+    def __getitem__(self, edge_index: PyEdgeIndex) -> PyAttributes: ...
+    def __iter__(self) -> PyEdgeIndexIterator: ...
+    def data(self) -> PyEdgeDataIterator: ...
+
+class PyAttribute:
+    def __init__(self, key: PyAttributeKey) -> None: ...
+    def __eq__(self, other: PyAttributeValue) -> PyQuery: ...  # type: ignore[override]
+    def __ne__(self, other: PyAttributeValue) -> PyQuery: ...  # type: ignore[override]
+    def __lt__(self, other: PyAttributeValue) -> PyQuery: ...
+    def __le__(self, other: PyAttributeValue) -> PyQuery: ...
+    def __gt__(self, other: PyAttributeValue) -> PyQuery: ...
+    def __ge__(self, other: PyAttributeValue) -> PyQuery: ...
+    def exists(self) -> PyQuery: ...
+
+class PyQuery:
+    @staticmethod
+    def parse(expression: str) -> PyQuery: ...
+    def __and__(self, other: PyQuery) -> PyQuery: ...
+    def __or__(self, other: PyQuery) -> PyQuery: ...
+    def __invert__(self) -> PyQuery: ...
+    def __bool__(self) -> bool: ...
+
+class GraphsterBaseError(Exception): ...
+
+class NodeNotFoundError(GraphsterBaseError): ...
+
+class NodeAlreadyExistsError(GraphsterBaseError): ...
+
+class EdgeNotFoundError(GraphsterBaseError): ...
+
+class ConversionError(GraphsterBaseError): ...
+
+class AttributeNotFoundError(GraphsterBaseError): ...
+
+class QueryParseError(GraphsterBaseError):
+    position: int
+
+class QueryTypeError(GraphsterBaseError): ...
+
+class NegativeCycleError(GraphsterBaseError): ...
+
+class NegativeWeightError(GraphsterBaseError): ...
+
+class CycleError(GraphsterBaseError): ...
+
+class ConvergenceError(GraphsterBaseError): ...
+
+def attr(key: PyAttributeKey) -> PyAttribute: ...
@@ -0,0 +1,692 @@
+"""Generate pyo3_graphster/_pyo3_graphster.pyi from the Rust sources.
+
+The stubs are derived from the ``#[pyclass]``, ``#[pymethods]``, ``#[pyfunction]``
+and ``create_exception!`` definitions of the pyo3-graphster crate. Run with
+``--check`` to fail when the checked-in stubs are out of date.
+"""
+
+import argparse
+import difflib
+import re
+import sys
+from dataclasses import dataclass, field
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+SOURCE_DIRECTORY = ROOT / "crates" / "pyo3-graphster" / "src"
+STUB_PATH = ROOT / "pyo3_graphster" / "_pyo3_graphster.pyi"
+
+LINE_LENGTH = 88
+INDENT = "    "
+
+GENERATED_NOTICE = (
+    "# This file is generated by scripts/generate_stubs.py. Do not edit it by hand."
+)
+
+TYPING_NAMES = [
+    "Any",
+    "Callable",
+    "Dict",
+    "Iterable",
+    "Iterator",
+    "List",
+    "Literal",
+    "Optional",
+    "Tuple",
+    "Union",
+]
+
+ALIASES = '''\
+PyAttributeValue: TypeAlias = Union[
+    int,
+    bool,
+    str,
+    float,
+    PyFloat32,
+    PyFloat64,
+    PyInt128,
+    PyInt16,
+    PyInt32,
+    PyInt64,
+    PyInt8,
+    PyString,
+    PyUInt128,
+    PyUInt16,
+    PyUInt32,
+    PyUInt64,
+    PyUInt8,
+    PyUsize,
+    None,
+]
+
+PyAttributeKey: TypeAlias = Union[
+    int,
+    bool,
+    str,
+    PyInt128,
+    PyInt16,
+    PyInt32,
+    PyInt64,
+    PyInt8,
+    PyString,
+    PyUInt128,
+    PyUInt16,
+    PyUInt32,
+    PyUInt64,
+    PyUInt8,
+    PyUsize,
+]
+
+PyNodeIndex: TypeAlias = PyAttributeKey
+PyEdgeIndex: TypeAlias = Union[int, PyUsize]
+PyAttributes: TypeAlias = Dict[PyAttributeKey, PyAttributeValue]
+PyTraversalStep: TypeAlias = Tuple[PyNodeIndex, int, Optional[PyNodeIndex]]
+PyTraversalEvent: TypeAlias = Tuple[
+    Literal["discover", "finish"], PyNodeIndex, int, Optional[PyNodeIndex]
+]
+'''
+
+# Methods returning an untyped `PyObject` need their Python type spelled out here.
+# Methods generated by a macro are keyed by the macro name instead of the class.
+RETURN_OVERRIDES = {
+    "PyCriticalPathAnalysis.critical_path": "List[PyNodeIndex]",
+    "PyCriticalPathAnalysis.earliest_finish": "Dict[PyNodeIndex, float]",
+    "PyCriticalPathAnalysis.earliest_start": "Dict[PyNodeIndex, float]",
+    "PyCriticalPathAnalysis.latest_finish": "Dict[PyNodeIndex, float]",
+    "PyCriticalPathAnalysis.latest_start": "Dict[PyNodeIndex, float]",
+    "PyCriticalPathAnalysis.slack": "Dict[PyNodeIndex, float]",
+    "PyDataGraph.add_edge": "PyEdgeIndex",
+    "PyDataGraph.add_edges": "List[PyEdgeIndex]",
+    "PyDataGraph.delete_edge_attribute": "PyAttributeValue",
+    "PyDataGraph.delete_node_attribute": "PyAttributeValue",
+    "PyDataGraph.remove_edge": "PyAttributes",
+    "PyDataGraph.remove_edges": "Dict[PyEdgeIndex, PyAttributes]",
+    "PyDataGraph.remove_node": "PyAttributes",
+    "PyDataGraph.remove_nodes": "Dict[PyNodeIndex, PyAttributes]",
+    "PyEdgeDataIterator.__next__": "Tuple[PyEdgeIndex, PyAttributes]",
+    "PyEdgeIndexIterator.__next__": "PyEdgeIndex",
+    "PyEdgesView.__getitem__": "PyAttributes",
+    "PyFloat32.__int__": "int",
+    "PyFloat64.__int__": "int",
+    "PyNodeDataIterator.__next__": "Tuple[PyNodeIndex, PyAttributes]",
+    "PyNodeIndexIterator.__next__": "PyNodeIndex",
+    "PyNodesView.__getitem__": "PyAttributes",
+    "PyTraversalIterator.__next__": "Union[PyNodeIndex, PyTraversalStep, PyTraversalEvent]",
+    "implement_graph_pymethods.all_pairs_shortest_path_lengths": "Dict[PyNodeIndex, Dict[PyNodeIndex, float]]",
+    "implement_graph_pymethods.astar_path": "Optional[List[PyNodeIndex]]",
+    "implement_graph_pymethods.betweenness_centrality": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.closeness_centrality": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.condensation": "Tuple[PyDataGraph, Dict[PyNodeIndex, List[PyNodeIndex]]]",
+    "implement_graph_pymethods.dag_longest_path": "List[PyNodeIndex]",
+    "implement_graph_pymethods.degree_centrality": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.degrees": "Dict[PyNodeIndex, int]",
+    "implement_graph_pymethods.edge_attributes": "PyAttributes",
+    "implement_graph_pymethods.edge_betweenness_centrality": "Dict[PyEdgeIndex, float]",
+    "implement_graph_pymethods.edge_endpoints": "Tuple[PyNodeIndex, PyNodeIndex]",
+    "implement_graph_pymethods.edge_endpoints_many": "List[Tuple[PyNodeIndex, PyNodeIndex]]",
+    "implement_graph_pymethods.eigenvector_centrality": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.find_cycle": "Optional[List[PyNodeIndex]]",
+    "implement_graph_pymethods.hits": "Tuple[Dict[PyNodeIndex, float], Dict[PyNodeIndex, float]]",
+    "implement_graph_pymethods.in_degrees": "Dict[PyNodeIndex, int]",
+    "implement_graph_pymethods.katz_centrality": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.node_attributes": "PyAttributes",
+    "implement_graph_pymethods.out_degrees": "Dict[PyNodeIndex, int]",
+    "implement_graph_pymethods.pagerank": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.shortest_path": "Optional[List[PyNodeIndex]]",
+    "implement_graph_pymethods.shortest_path_lengths": "Dict[PyNodeIndex, float]",
+    "implement_graph_pymethods.strongly_connected_components": "List[List[PyNodeIndex]]",
+    "implement_graph_pymethods.weakly_connected_components": "List[List[PyNodeIndex]]",
+}
+
+# Parameters taking an untyped `&Bound<'_, PyAny>` need their Python type here.
+PARAMETER_OVERRIDES = {
+    "PyDataGraph.remove_nodes.node_indices": "Iterable[PyNodeIndex]",
+    "PyDataGraph.remove_edges.edge_indices": "Iterable[PyEdgeIndex]",
+    "PyDataGraph.subgraph.node_indices": "Iterable[PyNodeIndex]",
+    "PyDataGraph.edge_subgraph.edge_indices": "Iterable[PyEdgeIndex]",
+    "implement_graph_pymethods.edge_endpoints_many.edge_indices": "Iterable[PyEdgeIndex]",
+    "PyNodesView.__contains__.node_index": "PyNodeIndex",
+    "PyNodesView.__getitem__.node_index": "PyNodeIndex",
+    "PyEdgesView.__contains__.edge_index": "PyEdgeIndex",
+    "PyEdgesView.__getitem__.edge_index": "PyEdgeIndex",
+}
+
+RICHCMP_METHODS = ["__eq__", "__ne__", "__lt__", "__le__", "__gt__", "__ge__"]
+
+RUST_TO_PYTHON_TYPES = {
+    "()": "None",
+    "bool": "bool",
+    "f32": "float",
+    "f64": "float",
+    "i8": "int",
+    "i16": "int",
+    "i32": "int",
+    "i64": "int",
+    "i128": "int",
+    "isize": "int",
+    "u8": "int",
+    "u16": "int",
+    "u32": "int",
+    "u64": "int",
+    "u128": "int",
+    "usize": "int",
+    "String": "str",
+    "&str": "str",
+    "PyDataFrame": "pl.DataFrame",
+    "PySeries": "pl.Series",
+    "PyQueryInput": "Union[PyQuery, str]",
+    "PyHeuristicInput": "Union[PyHeuristic, Callable[[PyNodeIndex, PyNodeIndex], float]]",
+    "PyPersonalization": "Union[Dict[PyNodeIndex, float], PyAttributeKey]",
+    "Direction": 'Literal["out", "in", "both"]',
+    "WeightSource": 'Literal["edge", "node"]',
+}
+
+PYTHON_EXCEPTION_BASES = {"PyException": "Exception"}
+
+EXCEPTION_ATTRIBUTES = {"QueryParseError": {"position": "int"}}
+
+SELF_REFERENCE = r"&?(?:Bound|PyRef(?:Mut)?)<'\w+,\s*Self>"
+GETTER_FIELD = r"#\[pyo3\(([^)]*\bget\b[^)]*)\)\]\s*(?:pub\s+)?(\w+)\s*:\s*([^,]+),"
+
+
+@dataclass
+class Method:
+    name: str
+    attributes: list[str]
+    parameters: list[tuple[str, str]]
+    return_type: str
+    macro: str | None = None
+
+
+@dataclass
+class PyClass:
+    name: str
+    fields: list[tuple[str, str]] = field(default_factory=lambda: [])
+    methods: list[Method] = field(default_factory=lambda: [])
+
+
+def strip_comments(source: str) -> str:
+    return re.sub(r"//[^\n]*", "", source)
+
+
+def find_closing(source: str, start: int) -> int:
+    """Return the index of the bracket closing the one at ``start``."""
+    pairs = {"(": ")", "[": "]", "{": "}", "<": ">"}
+    opening = source[start]
+    closing = pairs[opening]
+    depth = 0
+    index = start
+
+    while index < len(source):
+        character = source[index]
+
+        if character == '"':
+            index += 1
+            while source[index] != '"':
+                index += 2 if source[index] == "\\" else 1
+        elif opening == "<" and source[index : index + 2] == "->":
+            index += 1
+        elif character == opening:
+            depth += 1
+        elif character == closing:
+            depth -= 1
+            if depth == 0:
+                return index
+
+        index += 1
+
+    raise ValueError(f"Unbalanced {opening} at offset {start}")
+
+
+def split_top_level(text: str, separator: str = ",") -> list[str]:
+    parts: list[str] = []
+    depth = 0
+    current = ""
+
+    for index, character in enumerate(text):
+        if character in "([{<":
+            depth += 1
+        elif character in ")]}" or (character == ">" and text[index - 1] != "-"):
+            depth -= 1
+
+        if character == separator and depth == 0:
+            parts.append(current.strip())
+            current = ""
+        else:
+            current += character
+
+    if current.strip():
+        parts.append(current.strip())
+
+    return parts
+
+
+def parse_items(body: str) -> list[Method]:
+    """Parse the top-level `fn` items of an impl body, with their attributes."""
+    methods: list[Method] = []
+    attributes: list[str] = []
+    index = 0
+
+    while index < len(body):
+        if body.startswith("#[", index):
+            end = find_closing(body, index + 1)
+            attributes.append(" ".join(body[index + 2 : end].split()))
+            index = end + 1
+            continue
+
+        match = re.match(r"(?:pub(?:\([^)]*\))?\s+)?fn\s+(\w+)", body[index:])
+
+        if match is None:
+            if body[index] == "{":
+                index = find_closing(body, index)
+            elif not body[index].isspace():
+                attributes = []
+            index += 1
+            continue
+
+        name = match.group(1)
+        parameters_start = body.index("(", index + match.end(0) - 1)
+        parameters_end = find_closing(body, parameters_start)
+        body_start = body.index("{", parameters_end)
+        signature_tail = body[parameters_end + 1 : body_start]
+        return_match = re.search(
+            r"->\s*(.+?)\s*(?:where\b|$)", signature_tail, re.S
+        )
+
+        parameters: list[tuple[str, str]] = []
+        for parameter in split_top_level(body[parameters_start + 1 : parameters_end]):
+            if ":" not in parameter:
+                continue
+            parameter_name, parameter_type = parameter.split(":", 1)
+            parameters.append(
+                (parameter_name.strip(), " ".join(parameter_type.split()))
+            )
+
+        methods.append(
+            Method(
+                name=name,
+                attributes=attributes,
+                parameters=parameters,
+                return_type=return_match.group(1) if return_match else "()",
+            )
+        )
+        attributes = []
+        index = find_closing(body, body_start) + 1
+
+    return methods
+
+
+def convert_type(rust_type: str, class_name: str) -> str:
+    rust_type = rust_type.strip()
+
+    if rust_type in RUST_TO_PYTHON_TYPES:
+        return RUST_TO_PYTHON_TYPES[rust_type]
+    if rust_type == "Self" or re.fullmatch(SELF_REFERENCE, rust_type):
+        return class_name
+    if rust_type.startswith("&"):
+        dereferenced = re.sub(r"^&(?:'\w+\s+)?(?:mut\s+)?", "", rust_type)
+        return convert_type(dereferenced, class_name)
+    if rust_type.startswith("(") and rust_type.endswith(")"):
+        elements = split_top_level(rust_type[1:-1])
+        converted = [convert_type(element, class_name) for element in elements]
+        return f"Tuple[{', '.join(converted)}]"
+
+    match = re.fullmatch(r"([\w:]+)<(.*)>", rust_type, re.S)
+    if match is None:
+        return rust_type
+
+    container = match.group(1).split("::")[-1]
+    arguments = [
+        argument
+        for argument in split_top_level(match.group(2))
+        if not argument.startswith("'")
+    ]
+    converted = [convert_type(argument, class_name) for argument in arguments]
+
+    if container == "PyResult":
+        return converted[0]
+    if container == "Option":
+        return f"Optional[{converted[0]}]"
+    if container in ("Vec", "HashSet"):
+        return f"List[{converted[0]}]"
+    if container == "HashMap":
+        return f"Dict[{converted[0]}, {converted[1]}]"
+    if container == "Bound" and arguments[0] == "PyAny":
+        return "Any"
+    if container in ("Bound", "Py", "PyRef", "PyRefMut"):
+        return converted[0]
+
+    raise ValueError(f"Unsupported Rust type {rust_type} in {class_name}")
+
+
+def parse_signature(attributes: list[str]) -> list[str] | None:
+    for attribute in attributes:
+        match = re.match(r"pyo3\(.*signature\s*=\s*\(", attribute)
+        if match is not None:
+            start = match.end(0) - 1
+            end = find_closing(attribute, start)
+            return split_top_level(attribute[start + 1 : end])
+    return None
+
+
+def render_method(class_name: str | None, method: Method) -> list[str]:
+    qualified_name = method.name if class_name is None else f"{class_name}.{method.name}"
+    is_function = class_name is None
+    is_static = "staticmethod" in method.attributes
+    is_constructor = "new" in method.attributes
+    indent = "" if is_function else INDENT
+    owner = method.name if class_name is None else class_name
+
+    parameters = [
+        (name, rust_type)
+        for name, rust_type in method.parameters
+        if name not in ("self", "&self", "&mut self")
+        and not rust_type.startswith("Python<")
+        and not re.fullmatch(SELF_REFERENCE, rust_type)
+    ]
+    signature = parse_signature(method.attributes)
+
+    rendered_parameters: list[str] = [] if is_static or is_function else ["self"]
+    if signature is None:
+        signature = [name for name, _ in parameters]
+
+    types = dict(parameters)
+    for entry in signature:
+        name, _, default = entry.partition("=")
+        name = name.strip()
+
+        if name in ("*", "/"):
+            rendered_parameters.append(name)
+            continue
+
+        bare_name = name.lstrip("*")
+        annotation = PARAMETER_OVERRIDES.get(
+            f"{qualified_name}.{bare_name}",
+            PARAMETER_OVERRIDES.get(f"{method.macro}.{method.name}.{bare_name}"),
+        )
+        if annotation is None:
+            annotation = convert_type(types[bare_name], owner)
+        suffix = " = ..." if default else ""
+        rendered_parameters.append(f"{name}: {annotation}{suffix}")
+
+    if is_constructor:
+        name = "__init__"
+        return_type = "None"
+    else:
+        name = method.name
+        return_type = RETURN_OVERRIDES.get(
+            qualified_name, RETURN_OVERRIDES.get(f"{method.macro}.{method.name}")
+        )
+        if return_type is None:
+            if re.fullmatch(r"(?:PyResult<)?PyObject>?", method.return_type):
+                raise ValueError(f"Add a RETURN_OVERRIDES entry for {qualified_name}")
+            return_type = convert_type(method.return_type, owner)
+
+    decorators = [f"{indent}@staticmethod"] if is_static else []
+    if "getter" in method.attributes:
+        decorators.append(f"{indent}@property")
+
+    single_line = (
+        f"{indent}def {name}({', '.join(rendered_parameters)}) -> {return_type}: ..."
+    )
+    if len(single_line) <= LINE_LENGTH:
+        return decorators + [single_line]
+
+    return (
+        decorators
+        + [f"{indent}def {name}("]
+        + [f"{indent}{INDENT}{parameter}," for parameter in rendered_parameters]
+        + [f"{indent}) -> {return_type}: ..."]
+    )
+
+
+def render_richcmp(class_name: str, method: Method) -> list[str]:
+    if re.fullmatch(r"(?:PyResult<)?PyObject>?", method.return_type):
+        return [
+            f"{INDENT}def {name}(self, other: object) -> bool: ..."
+            for name in RICHCMP_METHODS
+        ]
+
+    return_type = convert_type(method.return_type, class_name)
+    other_type = convert_type(method.parameters[0][1], class_name)
+    lines = []
+
+    for name in RICHCMP_METHODS:
+        if name in ("__eq__", "__ne__"):
+            lines.append(
+                f"{INDENT}def {name}(self, other: {other_type}) -> {return_type}: ...  "
+                "# type: ignore[override]"
+            )
+        else:
+            lines.append(
+                f"{INDENT}def {name}(self, other: {other_type}) -> {return_type}: ..."
+            )
+
+    return lines
+
+
+def render_class(py_class: PyClass) -> list[str]:
+    lines = [f"class {py_class.name}:"]
+
+    for name, annotation in py_class.fields:
+        lines.append(f"{INDENT}{name}: {annotation}")
+
+    for method in py_class.methods:
+        if method.name == "__richcmp__":
+            lines.extend(render_richcmp(py_class.name, method))
+        else:
+            lines.extend(render_method(py_class.name, method))
+
+    if len(lines) == 1:
+        lines[0] += " ..."
+
+    return lines
+
+
+def substitute(text: str, replacements: dict[str, str]) -> str:
+    for placeholder, replacement in replacements.items():
+        text = re.sub(rf"\${placeholder}\b", replacement, text)
+    return text
+
+
+def collect_macros(source: str) -> dict[str, tuple[list[str], str]]:
+    """Map each `macro_rules!` name to its parameter names and body."""
+    macros: dict[str, tuple[list[str], str]] = {}
+
+    for match in re.finditer(r"macro_rules!\s+(\w+)\s*\{", source):
+        definition_end = find_closing(source, match.end(0) - 1)
+        definition = source[match.end(0) : definition_end]
+        matcher_start = definition.index("(")
+        matcher_end = find_closing(definition, matcher_start)
+        matcher = definition[matcher_start + 1 : matcher_end]
+        body_start = definition.index("{", matcher_end)
+        body = definition[body_start + 1 : find_closing(definition, body_start)]
+        macros[match.group(1)] = (re.findall(r"\$(\w+):", matcher), body)
+
+    return macros
+
+
+def expand_macro(
+    macros: dict[str, tuple[list[str], str]], name: str, arguments: list[str]
+) -> tuple[str, list[Method]] | None:
+    """Expand a method-generating macro into its class name and methods."""
+    parameter_names, body = macros[name]
+    replacements = dict(zip(parameter_names, arguments))
+    extra_methods: list[Method] = []
+
+    if "methods" in parameter_names and len(arguments) == len(parameter_names):
+        extra_methods = parse_items(arguments[-1].strip()[1:-1])
+        replacements.pop("methods")
+
+    body = substitute(body, replacements)
+    nested = re.search(r"(\w+)!\s*\(", body)
+
+    if nested is not None and nested.group(1) in macros:
+        start = nested.end(0) - 1
+        nested_arguments = split_top_level(body[start + 1 : find_closing(body, start)])
+        expanded = expand_macro(macros, nested.group(1), nested_arguments)
+        if expanded is None:
+            return None
+        class_name, methods = expanded
+    else:
+        impl = re.search(r"#\[pymethods\]\s*impl\s+(\w+)\s*\{", body)
+        if impl is None:
+            return None
+        class_name = impl.group(1)
+        impl_body = body[impl.end(0) : find_closing(body, impl.end(0) - 1)]
+        methods = parse_items(impl_body)
+        for method in methods:
+            method.macro = name
+
+    return class_name, methods + extra_methods
+
+
+def collect_classes(sources: list[str]) -> list[PyClass]:
+    classes: dict[str, PyClass] = {}
+    macros: dict[str, tuple[list[str], str]] = {}
+
+    for source in sources:
+        macros.update(collect_macros(source))
+
+    for source in sources:
+        for match in re.finditer(r"#\[pyclass(?:\(([^\]]*)\))?\]", source):
+            declaration = re.match(
+                r"(?:\s*#\[[^\]]*\])*\s*pub(?:\([^)]*\))?\s+struct\s+(\w+)",
+                source[match.end(0) :],
+            )
+            if declaration is None:
+                continue
+
+            py_class = PyClass(declaration.group(1))
+            classes[py_class.name] = py_class
+            struct_start = match.end(0) + declaration.end(0)
+
+            if source[struct_start:].lstrip().startswith("{"):
+                fields_start = source.index("{", struct_start)
+                fields = source[fields_start + 1 : find_closing(source, fields_start)]
+                for getter in re.finditer(GETTER_FIELD, fields):
+                    py_class.fields.append(
+                        (getter.group(2), convert_type(getter.group(3), py_class.name))
+                    )
+
+    for source in sources:
+        for match in re.finditer(r"#\[pymethods\]\s*impl\s+(\w+)\s*\{", source):
+            if source.rfind("macro_rules!", 0, match.start(0)) > source.rfind(
+                "\n}", 0, match.start(0)
+            ):
+                continue
+            body_start = match.end(0) - 1
+            body = source[body_start + 1 : find_closing(source, body_start)]
+            classes[match.group(1)].methods.extend(parse_items(body))
+
+        for match in re.finditer(r"^(\w+)!\s*\(", source, re.M):
+            if match.group(1) not in macros:
+                continue
+            start = match.end(0) - 1
+            arguments = split_top_level(source[start + 1 : find_closing(source, start)])
+            expanded = expand_macro(macros, match.group(1), arguments)
+            if expanded is None:
+                continue
+            class_name, methods = expanded
+            classes[class_name].methods.extend(methods)
+
+    return list(classes.values())
+
+
+def collect_functions(sources: list[str]) -> list[Method]:
+    functions: list[Method] = []
+
+    for source in sources:
+        for match in re.finditer(r"#\[pyfunction\]", source):
+            body_start = source.index("{", match.end(0))
+            end = find_closing(source, body_start)
+            functions.extend(parse_items(source[match.start(0) : end + 1]))
+
+    return functions
+
+
+def collect_exceptions(sources: list[str]) -> list[tuple[str, str]]:
+    exceptions: list[tuple[str, str]] = []
+
+    for source in sources:
+        for match in re.finditer(r"create_exception!\s*\(([^)]*)\)", source):
+            _, name, base = split_top_level(match.group(1))
+            exceptions.append((name, PYTHON_EXCEPTION_BASES.get(base, base)))
+
+    return exceptions
+
+
+def render_exception(name: str, base: str) -> str:
+    attributes = EXCEPTION_ATTRIBUTES.get(name)
+    if not attributes:
+        return f"class {name}({base}): ..."
+
+    lines = [f"class {name}({base}):"]
+    lines.extend(
+        f"{INDENT}{attribute}: {python_type}"
+        for attribute, python_type in attributes.items()
+    )
+
+    return "\n".join(lines)
+
+
+def generate() -> str:
+    sources = [
+        strip_comments(path.read_text())
+        for path in sorted(SOURCE_DIRECTORY.rglob("*.rs"))
+    ]
+
+    sections = [ALIASES.rstrip("\n")]
+    sections.extend(
+        "\n".join(render_class(py_class)) for py_class in collect_classes(sources)
+    )
+    sections.extend(
+        render_exception(name, base) for name, base in collect_exceptions(sources)
+    )
+    sections.extend(
+        "\n".join(render_method(None, function))
+        for function in collect_functions(sources)
+    )
+    body = "\n\n".join(sections) + "\n"
+
+    typing_names = [name for name in TYPING_NAMES if re.search(rf"\b{name}\b", body)]
+    imports = f"from typing import {', '.join(sorted(typing_names + ['TypeAlias']))}\n"
+    if "pl." in body:
+        imports += "\nimport polars as pl\n"
+
+    return f"{GENERATED_NOTICE}\n\n{imports}\n{body}"
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__)
+    parser.add_argument(
+        "--check",
+        action="store_true",
+        help="fail if the checked-in stubs differ from the generated ones",
+    )
+    arguments = parser.parse_args()
+
+    generated = generate()
+
+    if not arguments.check:
+        STUB_PATH.write_text(generated)
+        return 0
+
+    current = STUB_PATH.read_text()
+    if current == generated:
+        return 0
+
+    sys.stdout.writelines(
+        difflib.unified_diff(
+            current.splitlines(keepends=True),
+            generated.splitlines(keepends=True),
+            fromfile=str(STUB_PATH.relative_to(ROOT)),
+            tofile="generated",
+        )
+    )
+    print("\nStubs are out of date, run `make stubs` to regenerate them.")
+    return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())